@@ -46,10 +46,12 @@ pub struct Comment {
     /// The span of the comment text, with leading and trailing delimiters.
     pub span: Span,
 
-    /// Start of token this leading comment is attached to.
+    /// Start of token this leading comment is attached to,
+    /// or end of token this trailing comment is attached to.
     /// `/* Leading */ token`
     ///                ^ This start
-    /// NOTE: Trailing comment attachment is not computed yet.
+    /// `token // Trailing`
+    ///       ^ This end
     pub attached_to: u32,
 
     /// Line or block comment
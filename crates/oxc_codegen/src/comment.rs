@@ -1,6 +1,6 @@
 use rustc_hash::FxHashMap;
 
-use oxc_ast::{Comment, CommentKind};
+use oxc_ast::{Comment, CommentKind, CommentPosition};
 use oxc_syntax::identifier::is_line_terminator;
 
 use crate::{Codegen, LegalComment};
@@ -118,6 +118,29 @@ impl Codegen<'_> {
         }
     }
 
+    /// Print comments trailing a statement on the same line, e.g. `foo(); // comment`.
+    ///
+    /// Trailing comments are attached to the end of the token they follow,
+    /// so `end` is end of the statement's span.
+    ///
+    /// Trailing comments are never legal comments or annotation comments,
+    /// so they're only printed if `comments` option is enabled.
+    pub(crate) fn print_trailing_statement_comments(&mut self, end: u32) {
+        if !self.print_comments || !self.options.comments {
+            return;
+        }
+        let Some(comments) = self.comments.remove(&end) else { return };
+        let (comments, unused_comments): (Vec<_>, Vec<_>) =
+            comments.into_iter().partition(|comment| comment.position == CommentPosition::Trailing);
+        for comment in &comments {
+            self.print_hard_space();
+            self.print_comment(comment);
+        }
+        if !unused_comments.is_empty() {
+            self.comments.insert(end, unused_comments);
+        }
+    }
+
     pub(crate) fn print_annotation_comments(&mut self, node_start: u32) {
         if !self.options.print_annotation_comments() {
             return;
@@ -258,8 +258,12 @@ impl Gen for ExpressionStatement<'_> {
         p.print_expression(&self.expression);
         if self.expression.is_specific_id("let") {
             p.print_semicolon();
-        } else {
+        } else if p.options.minify {
             p.print_semicolon_after_statement();
+        } else {
+            p.print_semicolon();
+            p.print_trailing_statement_comments(self.span.end);
+            p.print_hard_newline();
         }
     }
 }
@@ -2074,7 +2078,26 @@ impl Gen for AssignmentTargetRest<'_> {
 
 impl GenExpr for SequenceExpression<'_> {
     fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
-        p.wrap(precedence >= self.precedence(), |p| {
+        let wrap = precedence >= self.precedence();
+        if wrap && p.options.multiline_sequence_expressions && !p.options.minify {
+            p.print_ascii_byte(b'(');
+            p.indent();
+            for (index, expr) in self.expressions.iter().enumerate() {
+                if index != 0 {
+                    p.print_comma();
+                }
+                p.print_soft_newline();
+                p.print_indent();
+                expr.print_expr(p, Precedence::Lowest, ctx.and_forbid_call(false));
+            }
+            p.dedent();
+            p.print_soft_newline();
+            p.print_indent();
+            p.print_ascii_byte(b')');
+            return;
+        }
+
+        p.wrap(wrap, |p| {
             p.print_expressions(&self.expressions, Precedence::Lowest, ctx.and_forbid_call(false));
         });
     }
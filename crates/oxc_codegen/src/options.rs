@@ -75,6 +75,23 @@ pub struct CodegenOptions {
     /// By default, the source map path is the same as the input source code
     /// (with a `.map` extension).
     pub source_map_path: Option<PathBuf>,
+
+    /// Print each expression of a parenthesized sequence expression on a new line.
+    ///
+    /// e.g. `x = (_C = class C {}, _C.x = _C.y, _C);` is printed as:
+    /// ```js
+    /// x = (
+    ///   _C = class C {},
+    ///   _C.x = _C.y,
+    ///   _C
+    /// );
+    /// ```
+    ///
+    /// Sequence expressions which are not wrapped in parentheses are printed on a single line.
+    /// Has no effect when `minify` is `true`.
+    ///
+    /// Default is `false`.
+    pub multiline_sequence_expressions: bool,
 }
 
 impl Default for CodegenOptions {
@@ -86,6 +103,7 @@ impl Default for CodegenOptions {
             annotation_comments: false,
             legal_comments: LegalComment::default(),
             source_map_path: None,
+            multiline_sequence_expressions: false,
         }
     }
 }
@@ -1,4 +1,4 @@
-use oxc_codegen::CodegenOptions;
+use oxc_codegen::{CodegenOptions, LegalComment};
 
 use crate::tester::{test, test_minify, test_minify_same, test_options, test_same};
 
@@ -356,6 +356,34 @@ fn vite_special_comments() {
     );
 }
 
+#[test]
+fn trailing_statement_comments() {
+    test("foo(); // foo\nbar();\n", "foo(); // foo\nbar();\n");
+    test("foo(); /* a */ /* b */\n", "foo(); /* a */ /* b */\n");
+    test("function f() {\nfoo(); // foo\n}", "function f() {\n\tfoo(); // foo\n}\n");
+    test("foo() // foo\n", "foo(); // foo\n");
+    test_options(
+        "foo(); // foo\n",
+        "foo();\n",
+        CodegenOptions { comments: false, ..CodegenOptions::default() },
+    );
+    test_options(
+        "foo(); // foo\n",
+        "foo();\n",
+        CodegenOptions { comments: false, annotation_comments: true, ..CodegenOptions::default() },
+    );
+    test_options(
+        "foo(); // foo\n",
+        "foo();\n",
+        CodegenOptions {
+            comments: false,
+            legal_comments: LegalComment::Inline,
+            ..CodegenOptions::default()
+        },
+    );
+    test_minify("foo(); // foo\nbar();", "foo();bar();");
+}
+
 // <https://github.com/javascript-compiler-hints/compiler-notations-spec/blob/main/pure-notation-spec.md#semantics>
 #[test]
 fn pure_comment() {
@@ -501,3 +529,33 @@ fn string() {
     );
     test_minify(r#"foo("\n")"#, "foo(`\n`);");
 }
+
+#[test]
+fn multiline_sequence_expressions() {
+    let source = "x = (_C = class C {}, _C.x = _C.y, _C);";
+    let compact = CodegenOptions::default();
+    test_options(source, "x = (_C = class C {}, _C.x = _C.y, _C);\n", compact);
+
+    let multiline =
+        CodegenOptions { multiline_sequence_expressions: true, ..CodegenOptions::default() };
+    test_options(
+        source,
+        "x = (\n\t_C = class C {},\n\t_C.x = _C.y,\n\t_C\n);\n",
+        multiline.clone(),
+    );
+    test_options(
+        "f((_C = class C { m() {} }, _C.x = 1, _C));",
+        "f((\n\t_C = class C {\n\t\tm() {}\n\t},\n\t_C.x = 1,\n\t_C\n));\n",
+        multiline.clone(),
+    );
+    // Not wrapped in parentheses
+    test_options("a, b;", "a, b;\n", multiline.clone());
+    test_options("function f() { return a, b; }", "function f() {\n\treturn a, b;\n}\n", multiline);
+
+    let minify = CodegenOptions {
+        minify: true,
+        multiline_sequence_expressions: true,
+        ..CodegenOptions::default()
+    };
+    test_options(source, "x=(_C=class C{},_C.x=_C.y,_C);", minify);
+}
@@ -466,6 +466,8 @@ mod oxc {
     pub mod no_optional_chaining;
     pub mod no_redundant_constructor_init;
     pub mod no_rest_spread_properties;
+    pub mod no_static_field_use_before_define;
+    pub mod no_this_in_static_initializer_warn;
     pub mod number_arg_out_of_range;
     pub mod only_used_in_recursion;
     pub mod uninvoked_array_callback;
@@ -848,6 +850,8 @@ oxc_macros::declare_all_lint_rules! {
     oxc::no_optional_chaining,
     oxc::no_redundant_constructor_init,
     oxc::no_rest_spread_properties,
+    oxc::no_static_field_use_before_define,
+    oxc::no_this_in_static_initializer_warn,
     oxc::number_arg_out_of_range,
     oxc::only_used_in_recursion,
     oxc::uninvoked_array_callback,
@@ -0,0 +1,277 @@
+use oxc_ast::{
+    AstKind, Visit,
+    ast::{
+        ArrowFunctionExpression, AssignmentExpression, AssignmentOperator, AssignmentTarget, Class,
+        ClassElement, Expression, Function, StaticMemberExpression,
+    },
+    visit::walk,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{ClassStaticReferences, SymbolTable};
+use oxc_span::{Atom, GetSpan, Span};
+use oxc_syntax::{scope::ScopeFlags, symbol::SymbolId};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn no_static_field_use_before_define_diagnostic(
+    name: &str,
+    read_span: Span,
+    definition_span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Static field `{name}` is read before it is defined"))
+        .with_help(format!(
+            "Static fields are initialized in order, so `{name}` is `undefined` here. Move the definition of `{name}` above this."
+        ))
+        .with_labels([
+            read_span.label(format!("`{name}` is read here")),
+            definition_span.label(format!("`{name}` is defined here")),
+        ])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoStaticFieldUseBeforeDefine;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports static property initializers and static blocks which read a static field of the
+    /// same class (via `this` or the class name) which is defined later in the class body.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Static fields are initialized in the order they're defined, so when an initializer reads
+    /// a field defined after it, the field has not been set yet and the read gives `undefined`.
+    ///
+    /// Reads inside functions (including arrow functions) are not reported, as they usually run
+    /// after the class has been fully initialized. Reads via the class name in static initializers
+    /// of nested classes are reported, as they run when the nested class is created.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// class C {
+    ///   static a = this.b;
+    ///   static {
+    ///     console.log(C.b);
+    ///   }
+    ///   static b = 1;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// class C {
+    ///   static b = 1;
+    ///   static a = this.b;
+    ///   static f = () => this.c;
+    ///   static c = 2;
+    /// }
+    /// ```
+    NoStaticFieldUseBeforeDefine,
+    oxc,
+    suspicious
+);
+
+impl Rule for NoStaticFieldUseBeforeDefine {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::Class(class) = node.kind() else {
+            return;
+        };
+
+        // Index of first definition of each static field.
+        // Static methods and accessors are defined when class is created, so reading them is fine.
+        let mut fields = FxHashMap::default();
+        let mut methods = FxHashSet::default();
+        for (index, element) in class.body.body.iter().enumerate() {
+            if !element.r#static() {
+                continue;
+            }
+            let Some(name) = element.static_name() else { continue };
+            match element {
+                ClassElement::PropertyDefinition(prop) if !prop.declare => {
+                    fields.entry(name).or_insert((index, prop.key.span()));
+                }
+                ClassElement::MethodDefinition(_) | ClassElement::AccessorProperty(_) => {
+                    methods.insert(name);
+                }
+                _ => {}
+            }
+        }
+        fields.retain(|name, _| !methods.contains(name));
+        if fields.is_empty() {
+            return;
+        }
+
+        // Static initializers can only read static fields via `this` or class name
+        let references = ClassStaticReferences::analyze(class, ctx.symbols());
+        if references.this.is_empty() && !references.class_name {
+            return;
+        }
+
+        let class_symbol_id = class.id.as_ref().and_then(|id| id.symbol_id.get());
+        for (index, element) in class.body.body.iter().enumerate() {
+            let mut visitor = StaticFieldReads::new(ctx.symbols(), class_symbol_id);
+            if !visitor.visit_static_initializer(element) {
+                continue;
+            }
+
+            for (name, read_span) in visitor.reads {
+                if let Some(&(field_index, definition_span)) = fields.get(name.as_str()) {
+                    if field_index > index {
+                        ctx.diagnostic(no_static_field_use_before_define_diagnostic(
+                            &name,
+                            read_span,
+                            definition_span,
+                        ));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Visitor which finds reads of static fields of a class (`this.x` or `C.x`), which are evaluated
+/// immediately. Functions are not entered, as they may be called later.
+struct StaticFieldReads<'a, 's> {
+    symbols: &'s SymbolTable,
+    class_symbol_id: Option<SymbolId>,
+    reads: Vec<(Atom<'a>, Span)>,
+    /// Depth of nested class static initializers, where `this` does not refer to the class
+    this_depth: u32,
+}
+
+impl<'a, 's> StaticFieldReads<'a, 's> {
+    fn new(symbols: &'s SymbolTable, class_symbol_id: Option<SymbolId>) -> Self {
+        Self { symbols, class_symbol_id, reads: vec![], this_depth: 0 }
+    }
+
+    /// Visit `element` if it's a static property initializer or static block.
+    /// Returns `false` if it's neither.
+    fn visit_static_initializer(&mut self, element: &ClassElement<'a>) -> bool {
+        match element {
+            ClassElement::PropertyDefinition(prop) if prop.r#static => {
+                if let Some(value) = &prop.value {
+                    self.visit_expression(value);
+                }
+            }
+            ClassElement::StaticBlock(block) => {
+                self.visit_statements(&block.body);
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Check if `expr` is `this` or a reference to the class name.
+    fn is_class(&self, expr: &Expression) -> bool {
+        match expr {
+            Expression::ThisExpression(_) => self.this_depth == 0,
+            Expression::Identifier(ident) => {
+                self.class_symbol_id.is_some()
+                    && ident.reference_id.get().is_some_and(|reference_id| {
+                        self.symbols.get_reference(reference_id).symbol_id() == self.class_symbol_id
+                    })
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<'a> Visit<'a> for StaticFieldReads<'a, '_> {
+    fn visit_static_member_expression(&mut self, expr: &StaticMemberExpression<'a>) {
+        if self.is_class(&expr.object) {
+            self.reads.push((expr.property.name, expr.span));
+        }
+        walk::walk_static_member_expression(self, expr);
+    }
+
+    fn visit_assignment_expression(&mut self, expr: &AssignmentExpression<'a>) {
+        // `this.x = 1` does not read `x`
+        if expr.operator == AssignmentOperator::Assign {
+            if let AssignmentTarget::StaticMemberExpression(member) = &expr.left {
+                if self.is_class(&member.object) {
+                    self.visit_expression(&expr.right);
+                    return;
+                }
+            }
+        }
+        walk::walk_assignment_expression(self, expr);
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        // `this` in a nested class's body refers to that class. Only visit parts of nested class
+        // which are evaluated immediately in outer `this` context.
+        self.visit_decorators(&class.decorators);
+        if let Some(super_class) = &class.super_class {
+            self.visit_expression(super_class);
+        }
+        for element in &class.body.body {
+            if element.computed() {
+                if let Some(key) = element.property_key() {
+                    self.visit_property_key(key);
+                }
+            }
+        }
+        // Nested class's static initializers are also evaluated immediately, when nested class is
+        // created. `this` in them refers to nested class, but class name still refers to this class.
+        self.this_depth += 1;
+        for element in &class.body.body {
+            self.visit_static_initializer(element);
+        }
+        self.this_depth -= 1;
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "class C { static b = 1; static a = this.b; }",
+        "class C { static b = 1; static a = C.b; }",
+        "class C { static a = () => this.b; static b = 1; }",
+        "class C { static a = function() { return this.b; }; static b = 1; }",
+        "class C { static { setTimeout(() => C.b); } static b = 1; }",
+        "class C { static a = this.m(); static m() {} static b = 1; }",
+        "class C { static a = this.b; static get b() { return 1; } }",
+        "class C { static a = this.b; b = 1; }",
+        "class C { a = this.b; static b = 1; }",
+        "class C { static { this.b = 1; } static b = 2; }",
+        "class C { static a = class { static x = this.b; }; static b = 1; }",
+        "class C { static a = class { static { this.b; } }; static b = 1; }",
+        "class C { static a = class { x = C.b; m() { return C.b; } }; static b = 1; }",
+        "class C { static a = class { static x = class { [this.b] = 1; }; }; static b = 'x'; }",
+        "class C { static a = D.b; static b = 1; }",
+        "class C { static a = (C) => C.b; static b = 1; }",
+        "class C { static b = 1; static a = this.b; static b = 2; }",
+        "class C { static a = this.b; declare static b: number; }",
+    ];
+
+    let fail = vec![
+        "class C { static a = this.b; static b = 1; }",
+        "class C { static a = C.b; static b = 1; }",
+        "class C { static { console.log(this.b); } static b = 1; }",
+        "class C { static a = this.b + this.c; static b = 1; static c = 2; }",
+        "class C { static a = this.b.c; static b = { c: 1 }; }",
+        "class C { static a = class { [this.b] = 1; }; static b = 'x'; }",
+        "class C { static { this.b += 1; } static b = 1; }",
+        "x = class C { static a = C.b; static 'b' = 1; }",
+        "class C { static a = class { static x = C.b; }; static b = 1; }",
+        "class C { static a = class { static { C.b; } }; static b = 1; }",
+    ];
+
+    Tester::new(
+        NoStaticFieldUseBeforeDefine::NAME,
+        NoStaticFieldUseBeforeDefine::PLUGIN,
+        pass,
+        fail,
+    )
+    .test_and_snapshot();
+}
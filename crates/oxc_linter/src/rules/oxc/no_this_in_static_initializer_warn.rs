@@ -0,0 +1,213 @@
+use oxc_ast::{AstKind, ast::Class};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::ClassStaticReferences;
+use oxc_span::Span;
+
+use crate::{AstNode, context::LintContext, fixer::RuleFixer, rule::Rule};
+
+fn no_this_in_static_initializer_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("`this` in a static initializer refers to the class")
+        .with_help("When class fields are transformed for older engines, `this` is replaced with a reference to the class")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoThisInStaticInitializerWarn;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports `this` in static property initializers and static blocks.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// In a static initializer, `this` refers to the class itself, not `undefined` or the
+    /// instance. This can be surprising, and it's the `this` which the class properties transform
+    /// rewrites when targeting older engines (`class C { static x = this; }` -> `class C {}; C.x = C;`).
+    /// Using the class name makes the meaning explicit.
+    ///
+    /// `this` in nested functions refers to a different `this`, so is not reported.
+    ///
+    /// The fix replaces `this` with the class name. If the class binding is reassigned anywhere,
+    /// the class name may no longer refer to the class, so the fix is only offered as a suggestion.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// class C {
+    ///   static x = this;
+    ///   static {
+    ///     this.y = 1;
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// class C {
+    ///   static x = C;
+    ///   static {
+    ///     C.y = 1;
+    ///   }
+    ///   static f = function() { return this; };
+    ///   z = this;
+    /// }
+    /// ```
+    NoThisInStaticInitializerWarn,
+    oxc,
+    restriction,
+    conditional_fix_suggestion
+);
+
+impl Rule for NoThisInStaticInitializerWarn {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ThisExpression(this_expr) = node.kind() else {
+            return;
+        };
+        let span = this_expr.span;
+
+        let Some(class) = get_static_initializer_class(node, span, ctx) else {
+            return;
+        };
+
+        let diagnostic = no_this_in_static_initializer_diagnostic(span);
+
+        // Replacing `this` with class name is only safe if class name is not shadowed
+        let class_name = class.id.as_ref().filter(|id| {
+            id.symbol_id.get().is_some()
+                && ctx.scopes().find_binding(node.scope_id(), &id.name) == id.symbol_id.get()
+        });
+        let Some(id) = class_name else {
+            ctx.diagnostic(diagnostic);
+            return;
+        };
+
+        // Class name only still refers to the class when initializer runs if binding is never
+        // reassigned. `class C { static x = this; } C = null;`
+        let is_never_written =
+            ctx.symbols().get_resolved_references(id.symbol_id()).all(|r| !r.is_write());
+        let fix = |fixer: RuleFixer<'_, 'a>| fixer.replace(span, id.name.as_str());
+        if is_never_written {
+            ctx.diagnostic_with_fix(diagnostic, fix);
+        } else {
+            ctx.diagnostic_with_suggestion(diagnostic, fix);
+        }
+    }
+}
+
+/// Get class whose static property initializer or static block `this` is in,
+/// if `this` refers to that class.
+fn get_static_initializer_class<'a>(
+    node: &AstNode<'a>,
+    span: Span,
+    ctx: &LintContext<'a>,
+) -> Option<&'a Class<'a>> {
+    for ancestor in ctx.nodes().ancestors(node.id()).skip(1) {
+        match ancestor.kind() {
+            // Functions and namespaces have their own `this`
+            AstKind::Function(_) | AstKind::TSModuleBlock(_) => return None,
+            // `this` in computed key or decorator, or in a nested class's static initializer,
+            // may refer to an outer class, so keep searching if not found
+            AstKind::Class(class) => {
+                if ClassStaticReferences::analyze(class, ctx.symbols()).this.contains(&span) {
+                    return Some(class);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[test]
+fn test() {
+    use crate::{fixer::FixKind, tester::Tester};
+
+    let pass = vec![
+        "this",
+        "function f() { return this; }",
+        "class C { x = this; }",
+        "class C { method() { return this; } }",
+        "class C { static method() { return this; } }",
+        "class C { static x = function() { return this; }; }",
+        "class C { static { (function() { this; })(); } }",
+        "class C { static [this.key] = 1; }",
+        "class C { static accessor x = function() { return this; }; }",
+        // Auto-accessor initializers are not moved out of the class
+        "class C { static accessor x = this; }",
+        "class C { static x = class { y = this; }; }",
+        "class C { static x = class { method() { return this; } }; }",
+        "class C { static x = C; }",
+    ];
+
+    let fail = vec![
+        "class C { static x = this; }",
+        "class C { static x = this.y; }",
+        "class C { static x = () => this; }",
+        "class C { static { this.x = 1; } }",
+        "x = class { static y = this; }",
+        "class C { static x = class { [this.key] = 1; }; }",
+        "class C { static x = (C) => this; }",
+        "class C { static { let C = 1; this; } }",
+        "class C { static x = this; } C = null;",
+    ];
+
+    let fix = vec![
+        ("class C { static x = this; }", "class C { static x = C; }", None, FixKind::SafeFix),
+        (
+            "class C { static x = () => this.y; }",
+            "class C { static x = () => C.y; }",
+            None,
+            FixKind::SafeFix,
+        ),
+        (
+            "class C { static { this.x = 1; } }",
+            "class C { static { C.x = 1; } }",
+            None,
+            FixKind::SafeFix,
+        ),
+        (
+            "x = class C { static y = this; }",
+            "x = class C { static y = C; }",
+            None,
+            FixKind::SafeFix,
+        ),
+        // No fix when class is anonymous or class name is shadowed
+        (
+            "x = class { static y = this; }",
+            "x = class { static y = this; }",
+            None,
+            FixKind::SafeFix,
+        ),
+        (
+            "class C { static x = (C) => this; }",
+            "class C { static x = (C) => this; }",
+            None,
+            FixKind::SafeFix,
+        ),
+        // Only a suggestion when class binding is reassigned
+        (
+            "class C { static x = this; } C = null;",
+            "class C { static x = this; } C = null;",
+            None,
+            FixKind::SafeFix,
+        ),
+        (
+            "class C { static x = this; } C = null;",
+            "class C { static x = C; } C = null;",
+            None,
+            FixKind::Suggestion,
+        ),
+    ];
+
+    Tester::new(
+        NoThisInStaticInitializerWarn::NAME,
+        NoThisInStaticInitializerWarn::PLUGIN,
+        pass,
+        fail,
+    )
+    .expect_fix(fix)
+    .test_and_snapshot();
+}
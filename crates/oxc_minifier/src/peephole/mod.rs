@@ -22,7 +22,7 @@ use rustc_hash::FxHashSet;
 use oxc_allocator::Vec;
 use oxc_ast::ast::*;
 use oxc_data_structures::stack::NonEmptyStack;
-use oxc_syntax::{es_target::ESTarget, scope::ScopeId};
+use oxc_syntax::{es_target::ESTarget, scope::ScopeId, symbol::SymbolId};
 use oxc_traverse::{ReusableTraverseCtx, Traverse, TraverseCtx, traverse_mut_with_ctx};
 
 use crate::ctx::Ctx;
@@ -42,6 +42,11 @@ pub struct PeepholeOptimizations {
     /// Track the current function as a stack.
     current_function:
         NonEmptyStack<(ScopeId, /* prev changed */ bool, /* current changed */ bool)>,
+    /// Bindings of `defineProperty` helper imported from Babel or Oxc runtime.
+    /// Collected on entering program in first walk.
+    define_property_helpers: FxHashSet<SymbolId>,
+    /// Temp vars of unused classes which have been removed, whose declarations are to be removed.
+    unused_class_temp_vars: FxHashSet<SymbolId>,
 }
 
 impl<'a> PeepholeOptimizations {
@@ -52,6 +57,8 @@ impl<'a> PeepholeOptimizations {
             prev_functions_changed: FxHashSet::default(),
             functions_changed: FxHashSet::default(),
             current_function: NonEmptyStack::new((ScopeId::new(0), true, false)),
+            define_property_helpers: FxHashSet::default(),
+            unused_class_temp_vars: FxHashSet::default(),
         }
     }
 
@@ -101,6 +108,34 @@ impl<'a> PeepholeOptimizations {
         }
     }
 
+    /// Collect bindings of `defineProperty` helper imported from Babel or Oxc runtime.
+    ///
+    /// `import _defineProperty from "@oxc-project/runtime/helpers/defineProperty";`
+    ///
+    /// Imports are not added or removed by minifier, so this only needs to be done once per build.
+    fn collect_define_property_helpers(&mut self, program: &Program<'a>) {
+        self.define_property_helpers.clear();
+        self.unused_class_temp_vars.clear();
+        for stmt in &program.body {
+            let Statement::ImportDeclaration(decl) = stmt else { continue };
+            if !matches!(
+                decl.source.value.as_str(),
+                "@babel/runtime/helpers/defineProperty"
+                    | "@babel/runtime/helpers/esm/defineProperty"
+                    | "@oxc-project/runtime/helpers/defineProperty"
+                    | "@oxc-project/runtime/helpers/esm/defineProperty"
+            ) {
+                continue;
+            }
+            let Some(specifiers) = &decl.specifiers else { continue };
+            for specifier in specifiers {
+                if let ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) = specifier {
+                    self.define_property_helpers.insert(specifier.local.symbol_id());
+                }
+            }
+        }
+    }
+
     pub fn commutative_pair<'x, A, F, G, RetF: 'x, RetG: 'x>(
         pair: (&'x A, &'x A),
         check_a: F,
@@ -129,8 +164,14 @@ impl<'a> PeepholeOptimizations {
 }
 
 impl<'a> Traverse<'a> for PeepholeOptimizations {
-    fn enter_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         self.enter_program_or_function(program.scope_id());
+        if self.iteration == 0 {
+            self.collect_define_property_helpers(program);
+        }
+        // Removing assignments to an exported binding is not safe.
+        // Exports are discarded at start of each walk, so must be collected every time.
+        ctx.collect_exported_symbols(program);
     }
 
     fn exit_program(&mut self, _program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
@@ -150,6 +191,7 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
             return;
         }
         let ctx = Ctx(ctx);
+        self.remove_unused_class_temp_var_declarations(stmts, ctx);
         self.minimize_statements(stmts, ctx);
     }
 
@@ -365,11 +407,17 @@ impl<'a> DeadCodeElimination {
 }
 
 impl<'a> Traverse<'a> for DeadCodeElimination {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.inner.collect_define_property_helpers(program);
+        ctx.collect_exported_symbols(program);
+    }
+
     fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         self.inner.remove_dead_code_exit_statement(stmt, Ctx(ctx));
     }
 
     fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        self.inner.remove_unused_class_temp_var_declarations(stmts, Ctx(ctx));
         self.inner.remove_dead_code_exit_statements(stmts, Ctx(ctx));
         stmts.retain(|stmt| !matches!(stmt, Statement::EmptyStatement(_)));
     }
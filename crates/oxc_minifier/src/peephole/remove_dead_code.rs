@@ -300,6 +300,19 @@ impl<'a, 'b> PeepholeOptimizations {
             }
         }
 
+        // Unused class is removed along with its temp var, whose declaration is removed
+        // in `remove_unused_class_temp_var_declarations`
+        if let Expression::SequenceExpression(sequence_expr) = &expr_stmt.expression {
+            if let Some(symbol_id) =
+                self.unused_class_with_static_properties_temp_var(sequence_expr, ctx)
+            {
+                self.unused_class_temp_vars.insert(symbol_id);
+                *stmt = ctx.ast.statement_empty(expr_stmt.span);
+                self.mark_current_function_as_changed();
+                return;
+            }
+        }
+
         if self.remove_unused_expression(&mut expr_stmt.expression, ctx) {
             *stmt = ctx.ast.statement_empty(expr_stmt.span);
             self.mark_current_function_as_changed();
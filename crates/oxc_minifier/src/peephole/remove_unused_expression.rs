@@ -1,9 +1,11 @@
+use oxc_allocator::Vec;
 use oxc_ast::ast::*;
 use oxc_ecmascript::{
     constant_evaluation::{DetermineValueType, IsLiteralValue, ValueType},
     side_effects::MayHaveSideEffects,
 };
 use oxc_span::GetSpan;
+use oxc_syntax::symbol::SymbolId;
 
 use crate::ctx::Ctx;
 
@@ -60,10 +62,178 @@ impl<'a> PeepholeOptimizations {
 
     fn fold_sequence_expression(&self, e: &mut Expression<'a>, ctx: Ctx<'a, '_>) -> bool {
         let Expression::SequenceExpression(sequence_expr) = e else { return false };
+        if self.unused_class_with_static_properties_temp_var(sequence_expr, ctx).is_some() {
+            return true;
+        }
         sequence_expr.expressions.retain_mut(|e| !self.remove_unused_expression(e, ctx));
         sequence_expr.expressions.is_empty()
     }
 
+    /// Check if sequence is a class with static properties, as output by class properties transform,
+    /// which can be removed when its result is unused. Returns temp var `_C`.
+    ///
+    /// `(_C = class {}, _defineProperty(_C, "y", 1), _C)`
+    /// `(_C = class {}, _C.y = 1, _C)` (loose mode)
+    ///
+    /// Only removed when:
+    /// * Class has no `extends` clause, and creating it has no side effects.
+    /// * `_defineProperty` is the helper imported from Babel or Oxc runtime.
+    /// * Property keys are literals (other than `"prototype"`, which is non-configurable),
+    ///   and values have no side effects.
+    /// * In loose mode, class has no static accessors, and keys are not `name`, `length`,
+    ///   or properties of `Function.prototype` which throw or have side effects when assigned to.
+    /// * `_C` is not exported, and has no references other than those in the sequence,
+    ///   so removing the assignment to it is not observable.
+    pub fn unused_class_with_static_properties_temp_var(
+        &self,
+        sequence_expr: &SequenceExpression<'a>,
+        ctx: Ctx<'a, '_>,
+    ) -> Option<SymbolId> {
+        let (first, mut rest) = sequence_expr.expressions.split_first()?;
+        let Expression::AssignmentExpression(assign_expr) = first else { return None };
+        if assign_expr.operator != AssignmentOperator::Assign {
+            return None;
+        }
+        let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assign_expr.left else {
+            return None;
+        };
+        let symbol_id = ctx.symbols().get_reference(ident.reference_id()).symbol_id()?;
+        let Expression::ClassExpression(class) = &assign_expr.right else { return None };
+        if class.super_class.is_some() || class.may_have_side_effects(&ctx) {
+            return None;
+        }
+
+        let is_class_temp_var = |expr: &Expression<'a>| {
+            matches!(expr, Expression::Identifier(ident)
+                if ctx.symbols().get_reference(ident.reference_id()).symbol_id() == Some(symbol_id))
+        };
+
+        // Trailing `_C` is optional
+        let mut reference_count = 1;
+        if let Some((last, init)) = rest.split_last() {
+            if is_class_temp_var(last) {
+                rest = init;
+                reference_count += 1;
+            }
+        }
+
+        for expr in rest {
+            let value = match expr {
+                Expression::CallExpression(call_expr) => {
+                    let Expression::Identifier(callee) = &call_expr.callee else { return None };
+                    if !ctx
+                        .symbols()
+                        .get_reference(callee.reference_id())
+                        .symbol_id()
+                        .is_some_and(|symbol_id| self.define_property_helpers.contains(&symbol_id))
+                    {
+                        return None;
+                    }
+                    let [object, key, value] = call_expr.arguments.as_slice() else { return None };
+                    if !object.as_expression().is_some_and(is_class_temp_var) {
+                        return None;
+                    }
+                    match key {
+                        Argument::StringLiteral(lit) if lit.value != "prototype" => {}
+                        Argument::NumericLiteral(_) => {}
+                        _ => return None,
+                    }
+                    value.as_expression()?
+                }
+                Expression::AssignmentExpression(assign_expr) => {
+                    if assign_expr.operator != AssignmentOperator::Assign
+                        || Self::class_has_static_accessor(class)
+                    {
+                        return None;
+                    }
+                    let member = assign_expr.left.as_member_expression()?;
+                    if !is_class_temp_var(member.object()) {
+                        return None;
+                    }
+                    match member {
+                        MemberExpression::StaticMemberExpression(member) => {
+                            if !Self::is_assignable_class_static_property(&member.property.name) {
+                                return None;
+                            }
+                        }
+                        MemberExpression::ComputedMemberExpression(member) => {
+                            match &member.expression {
+                                Expression::StringLiteral(lit)
+                                    if Self::is_assignable_class_static_property(&lit.value) => {}
+                                Expression::NumericLiteral(_) => {}
+                                _ => return None,
+                            }
+                        }
+                        MemberExpression::PrivateFieldExpression(_) => return None,
+                    }
+                    &assign_expr.right
+                }
+                _ => return None,
+            };
+            if value.may_have_side_effects(&ctx) {
+                return None;
+            }
+            reference_count += 1;
+        }
+
+        (!ctx.symbol_is_exported(symbol_id)
+            && ctx.symbols().get_resolved_reference_ids(symbol_id).len() == reference_count)
+            .then_some(symbol_id)
+    }
+
+    fn class_has_static_accessor(class: &Class<'a>) -> bool {
+        class.body.body.iter().any(|element| match element {
+            ClassElement::MethodDefinition(method) => method.r#static && method.kind.is_accessor(),
+            ClassElement::AccessorProperty(prop) => prop.r#static,
+            _ => false,
+        })
+    }
+
+    /// Check if assigning to a static property of a class without `extends` clause
+    /// creates an own data property, without throwing or other side effects.
+    fn is_assignable_class_static_property(name: &str) -> bool {
+        !matches!(name, "prototype" | "name" | "length" | "caller" | "arguments" | "__proto__")
+    }
+
+    /// Remove `var _C;` declarations of temp vars for unused classes removed by
+    /// `try_fold_expression_stmt`.
+    ///
+    /// `var _C; (_C = class {}, _C.y = 1, _C);` -> ``
+    pub fn remove_unused_class_temp_var_declarations(
+        &mut self,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        ctx: Ctx<'a, '_>,
+    ) {
+        if self.unused_class_temp_vars.is_empty() {
+            return;
+        }
+
+        let mut changed = false;
+        for stmt in stmts.iter_mut() {
+            let Statement::VariableDeclaration(decl) = stmt else { continue };
+            if decl.kind != VariableDeclarationKind::Var {
+                continue;
+            }
+            let len = decl.declarations.len();
+            decl.declarations.retain(|declarator| {
+                declarator.init.is_some()
+                    || !matches!(&declarator.id.kind, BindingPatternKind::BindingIdentifier(id)
+                        if self.unused_class_temp_vars.contains(&id.symbol_id()))
+            });
+            if decl.declarations.len() == len {
+                continue;
+            }
+            changed = true;
+            if decl.declarations.is_empty() {
+                *stmt = ctx.ast.statement_empty(decl.span);
+            }
+        }
+
+        if changed {
+            self.mark_current_function_as_changed();
+        }
+    }
+
     fn fold_logical_expression(&self, e: &mut Expression<'a>, ctx: Ctx<'a, '_>) -> bool {
         let Expression::LogicalExpression(logical_expr) = e else { return false };
         if !logical_expr.operator.is_coalesce() {
@@ -214,6 +384,21 @@ mod test {
         test("void x", "x");
     }
 
+    #[test]
+    fn test_unused_class_with_static_properties() {
+        let import = "import _defineProperty from '@oxc-project/runtime/helpers/defineProperty';";
+        test(
+            &format!("{import} var _C; (_C = class {{}}, _defineProperty(_C, 'y', 1), _C);"),
+            import,
+        );
+        test("var _C; (_C = class {}, _C.y = 1, _C);", "");
+        test("function f() { var _C; (_C = class {}, _C.y = 1, _C); }", "function f() {}");
+        test(
+            "var _C; (_C = class {}, _C.y = 1); foo(_C);",
+            "var _C; _C = class {}, _C.y = 1, foo(_C);",
+        );
+    }
+
     #[test]
     fn test_new_constructor_side_effect() {
         test("new WeakSet()", "");
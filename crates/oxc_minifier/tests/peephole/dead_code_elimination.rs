@@ -170,6 +170,93 @@ fn dce_var_hoisting() {
     );
 }
 
+// Output of class properties transform.
+//
+// Assignments to static properties are not removed, even if property is never read, because class
+// is an object which may be observed elsewhere (`Object.keys(C)`, `C[key]`, exports).
+// The exception is a class expression whose result is unused, and which is only referenced by its
+// temp var (`_C`) within the sequence which creates it, so it cannot be observed.
+#[test]
+fn dce_class_static_property_assignments() {
+    test_same("class C {} C.x = 1;");
+    test_same("var _C; class C {} _C = C; C.x = _C;");
+    test_same("var _C; x = (_C = class {}, _C.y = 1, _C);");
+    test("if (false) { class C {} C.x = 1; }", "");
+
+    // Unused class with static properties, as output by class properties transform
+    let import = "import _defineProperty from '@oxc-project/runtime/helpers/defineProperty';";
+    test(&format!("{import} var _C; (_C = class {{}}, _defineProperty(_C, 'y', 1), _C);"), import);
+    test(
+        &format!(
+            "{import} var _C; (_C = class C {{ m() {{}} }}, _defineProperty(_C, 'y', [1]), _defineProperty(_C, 2, 'z'));"
+        ),
+        import,
+    );
+    test(
+        "import _defineProperty from '@babel/runtime/helpers/defineProperty'; var _C; (_C = class {}, _defineProperty(_C, 'y', 1), _C);",
+        "import _defineProperty from '@babel/runtime/helpers/defineProperty';",
+    );
+    // Other declarators in temp var declaration are kept
+    test(
+        &format!("{import} var _C, x; (_C = class {{}}, _defineProperty(_C, 'y', 1), _C);"),
+        &format!("{import} var x;"),
+    );
+    test(
+        &format!(
+            "function f() {{ var _C; (_C = class {{}}, _defineProperty(_C, 'y', 1), _C); }} {import}"
+        ),
+        &format!("function f() {{}} {import}"),
+    );
+    // Result is used
+    test_same(&format!("{import} var _C; x = (_C = class {{}}, _defineProperty(_C, 'y', 1));"));
+    // `_C` is referenced elsewhere
+    test_same(&format!(
+        "{import} var _C; (_C = class {{}}, _defineProperty(_C, 'y', 1)); foo(_C);"
+    ));
+    test_same(&format!("{import} var _C; (_C = class {{}}, _defineProperty(_C, 'y', _C));"));
+    // `_C` is exported
+    test_same(&format!("{import} export var _C; (_C = class {{}}, _defineProperty(_C, 'y', 1));"));
+    // Class has `extends` clause
+    test_same(&format!(
+        "{import} var _C; (_C = class extends B {{}}, _defineProperty(_C, 'y', 1));"
+    ));
+    // Class has side effects
+    test_same(&format!(
+        "{import} var _C; (_C = class {{ [foo()]() {{}} }}, _defineProperty(_C, 'y', 1));"
+    ));
+    // Value has side effects
+    test_same(&format!("{import} var _C; (_C = class {{}}, _defineProperty(_C, 'y', foo()));"));
+    // Defining `prototype` throws
+    test_same(&format!("{import} var _C; (_C = class {{}}, _defineProperty(_C, 'prototype', 1));"));
+    // Computed key
+    test_same(&format!("{import} var _C; (_C = class {{}}, _defineProperty(_C, y, 1));"));
+    // `_defineProperty` is not the helper
+    test_same("var _C; (_C = class {}, _defineProperty(_C, 'y', 1));");
+    test_same(
+        "import _defineProperty from 'foo'; var _C; (_C = class {}, _defineProperty(_C, 'y', 1));",
+    );
+
+    // Loose mode output
+    test("var _C; (_C = class {}, _C.y = 1, _C);", "");
+    test("var _C; (_C = class C { static m() {} }, _C.y = [1], _C['a b'] = 'z', _C[2] = 3);", "");
+    test("var _C, x; (_C = class {}, _C.y = 1, _C);", "var x;");
+    // Result is used
+    test_same("var _C; x = (_C = class {}, _C.y = 1, _C);");
+    // `_C` is referenced elsewhere
+    test_same("var _C; (_C = class {}, _C.y = 1); foo(_C);");
+    // Value has side effects
+    test_same("var _C; (_C = class {}, _C.y = foo());");
+    // Assignment to static setter has side effects
+    test_same("var _C; (_C = class { static set y(v) { foo(v); } }, _C.y = 1);");
+    test_same("var _C; (_C = class { static accessor y; }, _C.y = 1);");
+    // Assignment to non-writable property, or `Function.prototype` accessor, throws
+    test_same("var _C; (_C = class {}, _C.name = 1);");
+    test_same("var _C; (_C = class {}, _C['length'] = 1);");
+    test_same("var _C; (_C = class {}, _C.caller = 1);");
+    // Compound assignment reads property
+    test_same("var _C; (_C = class {}, _C.y += 1);");
+}
+
 // https://github.com/terser/terser/blob/v5.9.0/test/compress/dead-code.js
 #[test]
 fn dce_from_terser() {
@@ -167,11 +167,90 @@ impl<'a> ParserImpl<'a> {
 
     fn parse_class_body(&mut self) -> Result<Box<'a, ClassBody<'a>>> {
         let span = self.start_span();
-        let class_elements =
-            self.parse_normal_list(Kind::LCurly, Kind::RCurly, Self::parse_class_element)?;
+        let class_elements = if self.options.recover_class_elements {
+            self.parse_class_elements_with_recovery()?
+        } else {
+            self.parse_normal_list(Kind::LCurly, Kind::RCurly, Self::parse_class_element)?
+        };
         Ok(self.ast.alloc_class_body(self.end_span(span), class_elements))
     }
 
+    /// Parse class elements, recovering from errors in individual class elements.
+    ///
+    /// Used when `recover_class_elements` option is enabled.
+    /// If an element fails to parse, record the error, skip to start of next element, and continue.
+    fn parse_class_elements_with_recovery(&mut self) -> Result<Vec<'a, ClassElement<'a>>> {
+        self.expect(Kind::LCurly)?;
+        let mut list = self.ast.vec();
+        loop {
+            let kind = self.cur_kind();
+            if kind == Kind::RCurly || kind == Kind::Eof {
+                break;
+            }
+
+            let checkpoint = self.checkpoint();
+            let ctx = self.ctx;
+            match self.parse_class_element() {
+                Ok(Some(element)) => list.push(element),
+                Ok(None) => break,
+                Err(error) => {
+                    let error_token_start = self.cur_token().start;
+                    self.rewind(checkpoint);
+                    self.ctx = ctx;
+                    self.state.decorators.clear();
+                    self.error(error);
+                    self.skip_to_next_class_element(error_token_start);
+                }
+            }
+        }
+        self.expect(Kind::RCurly)?;
+        Ok(list)
+    }
+
+    /// Skip tokens of a class element which failed to parse, until reaching start of next class element.
+    ///
+    /// Parser must be positioned at start of the failed element.
+    ///
+    /// Skip over brackets as a unit, and stop when outside brackets:
+    /// * After a `;`.
+    /// * Before a `}` which closes the class body.
+    /// * Before a token which can start a class element, if it's on a new line,
+    ///   or it's the token where the error occurred (e.g. missing semicolon in `a = 1 b = 2`).
+    ///
+    /// Always consumes at least one token, so can't get stuck in a loop.
+    fn skip_to_next_class_element(&mut self, error_token_start: u32) {
+        let mut depth = 0u32;
+        let mut is_first = true;
+        loop {
+            if depth == 0
+                && !is_first
+                && (self.cur_token().is_on_new_line || self.cur_token().start == error_token_start)
+                && self.at_class_element_start()
+            {
+                return;
+            }
+            is_first = false;
+
+            match self.cur_kind() {
+                Kind::Eof => return,
+                Kind::LCurly | Kind::LParen | Kind::LBrack => depth += 1,
+                Kind::RCurly if depth == 0 => return,
+                Kind::RCurly | Kind::RParen | Kind::RBrack => depth = depth.saturating_sub(1),
+                Kind::Semicolon if depth == 0 => {
+                    self.bump_any();
+                    return;
+                }
+                _ => {}
+            }
+            self.bump_any();
+        }
+    }
+
+    fn at_class_element_start(&self) -> bool {
+        let kind = self.cur_kind();
+        kind.is_class_element_name_start() || matches!(kind, Kind::At | Kind::Star)
+    }
+
     pub(crate) fn parse_class_element(&mut self) -> Result<Option<ClassElement<'a>>> {
         // skip empty class element `;`
         while self.at(Kind::Semicolon) {
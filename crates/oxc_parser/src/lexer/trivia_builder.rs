@@ -22,6 +22,9 @@ pub struct TriviaBuilder {
     /// Previous token kind, used to indicates comments are trailing from what kind
     previous_kind: Kind,
 
+    /// End of previous token, which trailing comments are attached to
+    previous_end: u32,
+
     pub(super) has_pure_comment: bool,
 }
 
@@ -33,6 +36,7 @@ impl Default for TriviaBuilder {
             processed: 0,
             saw_newline: true,
             previous_kind: Kind::Undetermined,
+            previous_end: 0,
             has_pure_comment: false,
         }
     }
@@ -63,6 +67,10 @@ impl TriviaBuilder {
         if self.processed < len {
             self.comments[len - 1].followed_by_newline = true;
             if !self.saw_newline {
+                // All unprocessed comments are trailing comments of previous token.
+                for comment in &mut self.comments[self.processed..] {
+                    comment.attached_to = self.previous_end;
+                }
                 self.processed = self.comments.len();
             }
         }
@@ -72,6 +80,7 @@ impl TriviaBuilder {
     pub fn handle_token(&mut self, token: Token) {
         let len = self.comments.len();
         self.previous_kind = token.kind;
+        self.previous_end = token.end;
         if self.processed < len {
             // All unprocessed preceding comments are leading comments attached to this token start.
             for comment in &mut self.comments[self.processed..] {
@@ -131,6 +140,12 @@ impl TriviaBuilder {
             // A line comment is always followed by a newline. This is never set in `handle_newline`.
             comment.followed_by_newline = true;
             if self.should_be_treated_as_trailing_comment() {
+                // This comment and all unprocessed preceding comments are trailing comments
+                // of previous token.
+                for comment in &mut self.comments[self.processed..] {
+                    comment.attached_to = self.previous_end;
+                }
+                comment.attached_to = self.previous_end;
                 self.processed = self.comments.len() + 1; // +1 to include this comment.
             }
             self.saw_newline = true;
@@ -193,7 +208,7 @@ mod test {
                 span: Span::new(76, 92),
                 kind: CommentKind::Block,
                 position: CommentPosition::Trailing,
-                attached_to: 0,
+                attached_to: 75,
                 preceded_by_newline: false,
                 followed_by_newline: false,
             },
@@ -201,7 +216,7 @@ mod test {
                 span: Span::new(93, 106),
                 kind: CommentKind::Line,
                 position: CommentPosition::Trailing,
-                attached_to: 0,
+                attached_to: 75,
                 preceded_by_newline: false,
                 followed_by_newline: true,
             },
@@ -241,7 +256,7 @@ token /* Trailing 1 */
                 span: Span::new(42, 58),
                 kind: CommentKind::Block,
                 position: CommentPosition::Trailing,
-                attached_to: 0,
+                attached_to: 41,
                 preceded_by_newline: false,
                 followed_by_newline: true,
             },
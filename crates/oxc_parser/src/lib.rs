@@ -209,6 +209,19 @@ pub struct ParseOptions {
     ///
     /// [`ParenthesizedExpression`]: oxc_ast::ast::ParenthesizedExpression
     pub preserve_parens: bool,
+
+    /// Recover from syntax errors in class elements.
+    ///
+    /// If this option is `true`, a class element which fails to parse produces a diagnostic,
+    /// and parser skips to the start of the next class element (after next `;`, before a token
+    /// which starts a new class element, or before `}` closing the class body) and continues parsing.
+    /// The malformed element is omitted from the AST, but the rest of the class is retained.
+    ///
+    /// Intended for editor / language server use, where a usable AST is desirable even when code
+    /// contains syntax errors.
+    ///
+    /// Default: `false`
+    pub recover_class_elements: bool,
 }
 
 impl Default for ParseOptions {
@@ -217,6 +230,7 @@ impl Default for ParseOptions {
             parse_regular_expression: false,
             allow_return_outside_function: false,
             preserve_parens: true,
+            recover_class_elements: false,
         }
     }
 }
@@ -583,7 +597,8 @@ impl<'a> ParserImpl<'a> {
 mod test {
     use std::path::Path;
 
-    use oxc_ast::ast::{CommentKind, Expression};
+    use oxc_ast::ast::{CommentKind, Expression, Statement};
+    use oxc_span::GetSpan;
 
     use super::*;
 
@@ -701,6 +716,43 @@ mod test {
         }
     }
 
+    #[test]
+    fn recover_class_elements() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "
+            class C {
+                a = 1 b = 2;
+                c = ;
+                method() { x y }
+                d = 3 +;
+                [e] = 4
+                get f() { return 5; }
+                g = (1 2)
+                h = { i: 6 }
+            }
+            foo();
+        ";
+
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.panicked);
+
+        let options = ParseOptions { recover_class_elements: true, ..ParseOptions::default() };
+        let ret = Parser::new(&allocator, source, source_type).with_options(options).parse();
+        assert!(!ret.panicked);
+        assert_eq!(ret.errors.len(), 5);
+        assert_eq!(ret.program.body.len(), 2);
+
+        let Statement::ClassDeclaration(class) = &ret.program.body[0] else { unreachable!() };
+        let names = class
+            .body
+            .body
+            .iter()
+            .map(|element| element.property_key().unwrap().span().source_text(source))
+            .collect::<std::vec::Vec<_>>();
+        assert_eq!(names, ["b", "e", "f", "h"]);
+    }
+
     #[test]
     fn memory_leak() {
         let allocator = Allocator::default();
@@ -1,5 +1,7 @@
 mod builder;
+mod static_references;
 mod table;
 
 pub use builder::ClassTableBuilder;
+pub use static_references::ClassStaticReferences;
 pub use table::ClassTable;
@@ -0,0 +1,121 @@
+use oxc_ast::{
+    ast::*,
+    visit::{Visit, walk},
+};
+use oxc_span::Span;
+use oxc_syntax::{scope::ScopeFlags, symbol::SymbolId};
+
+use crate::SymbolTable;
+
+/// Whether static property initializers and static blocks of a class contain references to
+/// `this` or the class name.
+///
+/// Such code needs special treatment if it's moved outside the class, as class properties transform does.
+/// e.g. `class C { static x = this; static y = C; }` -> `class C {}; C.x = C; C.y = C;`
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ClassStaticReferences {
+    /// Spans of `this` in static property initializers and static blocks
+    /// (excluding `this` in nested functions, which refers to a different `this`).
+    pub this: Vec<Span>,
+    /// `true` if any static property initializer or static block contains a reference to class name.
+    pub class_name: bool,
+}
+
+impl ClassStaticReferences {
+    /// Find references to `this` and class name in static property initializers and static blocks of `class`.
+    pub fn analyze(class: &Class<'_>, symbols: &SymbolTable) -> Self {
+        let class_symbol_id = class.id.as_ref().and_then(|id| id.symbol_id.get());
+        let mut visitor = StaticReferencesVisitor {
+            symbols,
+            class_symbol_id,
+            this_depth: 0,
+            references: Self::default(),
+        };
+
+        for element in &class.body.body {
+            match element {
+                ClassElement::PropertyDefinition(prop) if prop.r#static => {
+                    if let Some(value) = &prop.value {
+                        visitor.visit_expression(value);
+                    }
+                }
+                ClassElement::StaticBlock(block) => {
+                    visitor.visit_statements(&block.body);
+                }
+                _ => {}
+            }
+        }
+
+        visitor.references
+    }
+}
+
+/// Visitor which finds `this` and references to class name.
+///
+/// Tracks `this_depth` in same way as class properties transform's `StaticVisitor` does.
+/// `this_depth == 0` when `this` refers to the class.
+struct StaticReferencesVisitor<'s> {
+    symbols: &'s SymbolTable,
+    class_symbol_id: Option<SymbolId>,
+    this_depth: u32,
+    references: ClassStaticReferences,
+}
+
+impl<'a> Visit<'a> for StaticReferencesVisitor<'_> {
+    fn visit_this_expression(&mut self, it: &ThisExpression) {
+        if self.this_depth == 0 {
+            self.references.this.push(it.span);
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let Some(class_symbol_id) = self.class_symbol_id else { return };
+        let Some(reference_id) = ident.reference_id.get() else { return };
+        if self.symbols.get_reference(reference_id).symbol_id() == Some(class_symbol_id) {
+            self.references.class_name = true;
+        }
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        self.this_depth += 1;
+        walk::walk_function(self, func, flags);
+        self.this_depth -= 1;
+    }
+
+    fn visit_static_block(&mut self, block: &StaticBlock<'a>) {
+        self.this_depth += 1;
+        walk::walk_static_block(self, block);
+        self.this_depth -= 1;
+    }
+
+    fn visit_ts_module_block(&mut self, block: &TSModuleBlock<'a>) {
+        self.this_depth += 1;
+        walk::walk_ts_module_block(self, block);
+        self.this_depth -= 1;
+    }
+
+    fn visit_property_definition(&mut self, prop: &PropertyDefinition<'a>) {
+        // Decorators and computed key of a nested class's property are in outer `this` context
+        self.visit_decorators(&prop.decorators);
+        if prop.computed {
+            self.visit_property_key(&prop.key);
+        }
+        if let Some(value) = &prop.value {
+            self.this_depth += 1;
+            self.visit_expression(value);
+            self.this_depth -= 1;
+        }
+    }
+
+    fn visit_accessor_property(&mut self, prop: &AccessorProperty<'a>) {
+        self.visit_decorators(&prop.decorators);
+        if prop.computed {
+            self.visit_property_key(&prop.key);
+        }
+        if let Some(value) = &prop.value {
+            self.this_depth += 1;
+            self.visit_expression(value);
+            self.this_depth -= 1;
+        }
+    }
+}
@@ -12,6 +12,7 @@ use oxc_ast::{
 };
 use oxc_cfg::ControlFlowGraph;
 use oxc_span::{GetSpan, SourceType, Span};
+use oxc_syntax::class::ClassId;
 // Re-export flags and ID types
 pub use oxc_syntax::{
     node::{NodeFlags, NodeId},
@@ -36,6 +37,7 @@ mod symbol;
 mod unresolved_stack;
 
 pub use builder::{SemanticBuilder, SemanticBuilderReturn};
+pub use class::ClassStaticReferences;
 pub use jsdoc::{JSDoc, JSDocFinder, JSDocTag};
 pub use node::{AstNode, AstNodes};
 pub use scope::ScopeTree;
@@ -125,6 +127,19 @@ impl<'a> Semantic<'a> {
         &self.classes
     }
 
+    /// Get whether static property initializers and static blocks of a class
+    /// reference `this` or the class name.
+    ///
+    /// See [`ClassStaticReferences`].
+    ///
+    /// # Panics
+    /// Panics if `class_id` is not valid.
+    pub fn class_static_references(&self, class_id: ClassId) -> ClassStaticReferences {
+        let node_id = self.classes.get_node_id(class_id);
+        let class = self.nodes.kind(node_id).as_class().unwrap();
+        ClassStaticReferences::analyze(class, &self.symbols)
+    }
+
     /// Get a mutable reference to the [`ScopeTree`].
     pub fn scopes_mut(&mut self) -> &mut ScopeTree {
         &mut self.scopes
@@ -1,5 +1,7 @@
 use std::{fmt, mem};
 
+use rustc_hash::FxHashSet;
+
 use oxc_allocator::{Allocator, FromIn, Vec as ArenaVec};
 use oxc_ast::ast::{Expression, IdentifierReference};
 use oxc_index::{Idx, IndexVec};
@@ -321,6 +323,37 @@ impl SymbolTable {
         });
     }
 
+    /// Move references from one symbol to another.
+    ///
+    /// Equivalent to calling [`SymbolTable::delete_resolved_reference`] and
+    /// [`SymbolTable::add_resolved_reference`] for each reference, but only searches references
+    /// of `from_symbol_id` once, so is much faster when moving many references.
+    ///
+    /// Does not alter the [`Reference`]s themselves. Caller must update them with
+    /// [`Reference::set_symbol_id`].
+    ///
+    /// # Panics
+    /// Panics if any of `reference_ids` is not a resolved reference for `from_symbol_id`.
+    pub fn move_resolved_references(
+        &mut self,
+        from_symbol_id: SymbolId,
+        to_symbol_id: SymbolId,
+        reference_ids: &[ReferenceId],
+    ) {
+        let moving_ids = reference_ids.iter().copied().collect::<FxHashSet<_>>();
+        self.inner.with_dependent_mut(|_allocator, inner| {
+            let from_reference_ids = &mut inner.resolved_references[from_symbol_id.index()];
+            let len_before = from_reference_ids.len();
+            from_reference_ids.retain(|id| !moving_ids.contains(id));
+            assert!(
+                len_before - from_reference_ids.len() == moving_ids.len(),
+                "Not all references being moved are resolved references of `from_symbol_id`"
+            );
+
+            inner.resolved_references[to_symbol_id.index()].extend_from_slice(reference_ids);
+        });
+    }
+
     pub fn reserve(&mut self, additional_symbols: usize, additional_references: usize) {
         self.spans.reserve(additional_symbols);
         self.flags.reserve(additional_symbols);
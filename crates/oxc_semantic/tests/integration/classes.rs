@@ -39,3 +39,38 @@ fn test_class_with_ts() {
     .has_accessor("ap")
     .has_accessor("pap");
 }
+
+#[test]
+fn test_class_static_references() {
+    SemanticTester::js("class Foo { static x = this; }")
+        .has_class("Foo")
+        .has_static_references(true, false);
+
+    SemanticTester::js("class Foo { static { Foo.y(); } }")
+        .has_class("Foo")
+        .has_static_references(false, true);
+
+    SemanticTester::js("let X = class Foo { static x = () => [this, Foo]; }")
+        .has_class("Foo")
+        .has_static_references(true, true);
+
+    // Class name in nested function is a reference, `this` in nested function is not
+    SemanticTester::js("class Foo { static x = function() { return [this, Foo]; }; }")
+        .has_class("Foo")
+        .has_static_references(false, true);
+
+    // `this` in computed key of nested class refers to outer class
+    SemanticTester::js("class Foo { static x = class { [this] = 1; y = this; }; }")
+        .has_class("Foo")
+        .has_static_references(true, false);
+
+    // Instance properties and methods are not static initializers
+    SemanticTester::js("class Foo { x = [this, Foo]; static y() { return [this, Foo]; } }")
+        .has_class("Foo")
+        .has_static_references(false, false);
+
+    // Shadowed class name is not a reference to class
+    SemanticTester::js("class Foo { static x = (Foo) => Foo; }")
+        .has_class("Foo")
+        .has_static_references(false, false);
+}
@@ -56,4 +56,19 @@ impl<'a> ClassTester<'a> {
         debug_assert!(method.is_some(), "Expected accessor `{name}` not found");
         self
     }
+
+    pub fn has_static_references(&self, this: bool, class_name: bool) -> &Self {
+        let references = self.semantic.class_static_references(self.class_id);
+        debug_assert!(
+            references.this.is_empty() != this,
+            "Expected `this` in static initializers: {this}, found: {:?}",
+            references.this
+        );
+        debug_assert!(
+            references.class_name == class_name,
+            "Expected class name in static initializers: {class_name}, found: {}",
+            references.class_name
+        );
+        self
+    }
 }
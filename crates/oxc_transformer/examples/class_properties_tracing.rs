@@ -0,0 +1,66 @@
+#![expect(clippy::print_stdout)]
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{ESTarget, TransformOptions, Transformer};
+use pico_args::Arguments;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+// Instruction:
+// create a `test.js`,
+// run `cargo run -p oxc_transformer --features tracing --example class_properties_tracing`
+//
+// Timing of class properties transform is logged for each class, static property initializer
+// and static block, with source position of each. e.g.:
+//
+// ```
+// TRACE transform_class_elements:transform_static_initializer{start=21 end=25 walk_deep=true}: close time.busy=2.1µs time.idle=1.2µs
+// TRACE transform_class_elements{start=0 end=28}: close time.busy=15.4µs time.idle=2.0µs
+// ```
+//
+// Each scope which is reparented to outside the class is also logged, with old and new parent scope.
+// Scope flags show the kind of node which owns the scope (e.g. `Function | Arrow` for arrow function):
+//
+// ```
+// TRACE transform_class_elements{start=17 end=88}:transform_static_initializer{start=42 end=52 walk_deep=true}: reparent scope scope_id=ScopeId(3) flags=ScopeFlags(StrictMode | Function | Arrow) node_id=NodeId(11) old_parent_id=Some(ScopeId(2)) new_parent_id=ScopeId(1)
+// ```
+
+fn main() {
+    let mut args = Arguments::from_env();
+    let name = args.free_from_str().unwrap_or_else(|_| "test.js".to_string());
+
+    tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::TRACE)
+        .with_span_events(FmtSpan::CLOSE)
+        .init();
+
+    let path = Path::new(&name);
+    let source_text =
+        std::fs::read_to_string(path).unwrap_or_else(|err| panic!("{name} not found.\n{err}"));
+    let allocator = Allocator::default();
+    let source_type = SourceType::from_path(path).unwrap();
+
+    let ret = Parser::new(&allocator, &source_text, source_type).parse();
+    let mut program = ret.program;
+
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+
+    let transform_options = TransformOptions::from(ESTarget::ES2021);
+    let ret = Transformer::new(&allocator, path, &transform_options).build_with_symbols_and_scopes(
+        symbols,
+        scopes,
+        &mut program,
+    );
+
+    if !ret.errors.is_empty() {
+        println!("Transformer Errors:");
+        for error in ret.errors {
+            let error = error.with_source_code(source_text.clone());
+            println!("{error:?}");
+        }
+    }
+}
@@ -11,6 +11,9 @@
 //! self.ctx.statement_injector.insert_after(address, statement);
 //! self.ctx.statement_injector.insert_many_after(address, statements);
 //! ```
+//!
+//! A transform which replaces a statement with a new one can carry over statements other transforms
+//! have already requested to insert around the old statement with `move_insertions`.
 
 use std::cell::RefCell;
 
@@ -153,6 +156,44 @@ impl<'a> StatementInjectorStore<'a> {
             stmts.into_iter().map(|stmt| AdjacentStatement { stmt, direction: Direction::After }),
         );
     }
+
+    /// Move all statements to be inserted before / after `old_target` to `new_target`.
+    ///
+    /// Use this when replacing a statement with a new one, after other transforms may already
+    /// have requested insertions around the old statement.
+    /// Moved statements are placed before any statements already registered for `new_target`.
+    #[inline]
+    pub fn move_insertions<A1, A2>(&self, old_target: &A1, new_target: &A2)
+    where
+        A1: GetAddress,
+        A2: GetAddress,
+    {
+        self.move_insertions_address(old_target.address(), new_target.address());
+    }
+
+    fn move_insertions_address(&self, old_target: Address, new_target: Address) {
+        let mut insertions = self.insertions.borrow_mut();
+        let Some(mut moved_stmts) = insertions.remove(&old_target) else { return };
+        let Some(mut existing_stmts) = insertions.remove(&new_target) else {
+            insertions.insert(new_target, moved_stmts);
+            return;
+        };
+
+        // Both lists have all `Before` statements ahead of all `After` statements.
+        // Merge them maintaining that order: moved befores, existing befores, moved afters, existing afters.
+        let first_after_index = |stmts: &Vec<AdjacentStatement<'a>>| {
+            stmts
+                .iter()
+                .position(|s| matches!(s.direction, Direction::After))
+                .unwrap_or(stmts.len())
+        };
+        let moved_after_stmts = moved_stmts.split_off(first_after_index(&moved_stmts));
+        let existing_after_stmts = existing_stmts.split_off(first_after_index(&existing_stmts));
+        moved_stmts.extend(existing_stmts);
+        moved_stmts.extend(moved_after_stmts);
+        moved_stmts.extend(existing_after_stmts);
+        insertions.insert(new_target, moved_stmts);
+    }
 }
 
 // Internal methods
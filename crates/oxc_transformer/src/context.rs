@@ -33,6 +33,9 @@ pub struct TransformCtx<'a> {
 
     pub assumptions: CompilerAssumptions,
 
+    /// Prefix for name of class temp vars created by class properties transform
+    pub class_temp_var_prefix: Option<String>,
+
     // Helpers
     /// Manage helper loading
     pub helper_loader: HelperLoaderStore<'a>,
@@ -60,6 +63,7 @@ impl TransformCtx<'_> {
             source_text: "",
             module: options.env.module,
             assumptions: options.assumptions,
+            class_temp_var_prefix: options.class_temp_var_prefix.clone(),
             helper_loader: HelperLoaderStore::new(&options.helper_loader),
             module_imports: ModuleImportsStore::new(),
             var_declarations: VarDeclarationsStore::new(),
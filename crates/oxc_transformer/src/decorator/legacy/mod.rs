@@ -278,6 +278,7 @@ impl<'a> LegacyDecorator<'a, '_> {
         if class_or_constructor_parameter_is_decorated {
             return Some(self.transform_class_declaration_with_class_decorators(
                 class,
+                stmt_address,
                 has_private_in_expression_in_decorator,
                 ctx,
             ));
@@ -299,6 +300,7 @@ impl<'a> LegacyDecorator<'a, '_> {
     fn transform_class_declaration_with_class_decorators(
         &self,
         class: &mut Class<'a>,
+        stmt_address: Address,
         has_private_in_expression_in_decorator: bool,
         ctx: &mut TraverseCtx<'a>,
     ) -> (BoundIdentifier<'a>, Statement<'a>) {
@@ -447,6 +449,14 @@ impl<'a> LegacyDecorator<'a, '_> {
         );
         let statement = Statement::from(var_declaration);
 
+        // Class declaration is replaced by `let C = class C {}`, so statements which other transforms
+        // inserted around the original class declaration need to go around the new statement instead.
+        // e.g. class-properties transform has already moved static properties out of the class
+        // on exiting the class (before this runs on exiting the statement).
+        // This results in static properties being initialized before decorators are applied,
+        // same as TypeScript:
+        // `let C = class C {}; _defineProperty(C, "x", 1); C = _decorate([dec], C);`
+        self.ctx.statement_injector.move_insertions(&stmt_address, &statement);
         self.ctx.statement_injector.insert_many_after(&statement, decoration_stmts);
 
         (class_binding, statement)
@@ -3,8 +3,8 @@
 
 use indexmap::map::Entry;
 use oxc_allocator::{Address, GetAddress};
-use oxc_ast::{NONE, ast::*};
-use oxc_span::SPAN;
+use oxc_ast::{NONE, Visit, ast::*, visit::walk};
+use oxc_span::{GetSpan, SPAN};
 use oxc_syntax::{
     node::NodeId,
     reference::ReferenceFlags,
@@ -70,6 +70,16 @@ impl<'a> ClassProperties<'a, '_> {
         let class_scope_id = class.scope_id().get().unwrap();
         let has_super_class = class.super_class().is_some();
 
+        // Convert static properties to static blocks, if enabled.
+        // Must be done before 1st pass below, so they're treated as static blocks from then on.
+        // `class C { static x = 1; }` -> `class C { static { _defineProperty(this, "x", 1); } }`
+        if self.static_props_in_static_blocks
+            && !self.transform_static_blocks
+            && Self::can_convert_static_props_to_static_blocks(body)
+        {
+            self.convert_static_props_to_static_blocks(body, ctx);
+        }
+
         // Check if class has any properties, private methods, or static blocks.
         // Locate constructor (if class has one).
         let mut instance_prop_count = 0;
@@ -167,45 +177,85 @@ impl<'a> ClassProperties<'a, '_> {
             }
         }
 
-        // Exit if nothing to transform
+        // Exit if nothing to transform.
+        // Class is also skipped on exit, so static initializer visitor is never created for it.
         if instance_prop_count == 0
             && !has_static_prop
             && !has_static_private_method_or_static_block
             && !has_instance_private_method
         {
             self.classes_stack.push(ClassDetails {
+                scope_id: class_scope_id,
                 is_declaration,
                 is_transform_required: false,
                 private_props: if private_props.is_empty() { None } else { Some(private_props) },
                 bindings: ClassBindings::dummy(),
+                static_initializer_strictness: None,
             });
             return;
         }
 
+        // If class name binding is never reassigned, class name can be used in place of temp var.
+        // `class C { static x = C.y; }` -> `class C {}; C.x = C.y;`
+        // This applies equally to references in nested functions which are evaluated later
+        // (`static x = () => C;`). Whether binding is mutated is a property of the binding, not of
+        // when it's read - it's mutated if it's written to anywhere, so a lazy reference can't
+        // observe a different value from an immediate one.
+        let use_name_for_temp = self.skip_temp_var_for_unmutated_class
+            && is_declaration
+            && class_name_binding
+                .as_ref()
+                .is_some_and(|binding| Self::class_name_is_unmutated(binding.symbol_id, ctx));
+
         // Initialize class binding vars.
         // Static prop in class expression or anonymous `export default class {}` always require
         // temp var for class. Static prop in class declaration doesn't.
         let need_temp_var = has_static_prop && (!is_declaration || class_name_binding.is_none());
 
+        // If `inline_class_temp_var` option is enabled, temp var for class expression may turn out
+        // not to be needed. So don't create it in entry phase. It's created on demand if anything
+        // references it, or in exit phase if it's needed then. See `create_class_temp_var_placeholder`.
+        let defer_temp_var = need_temp_var && !is_declaration && self.inline_class_temp_var;
+        let mut temp_var_is_created = need_temp_var && !defer_temp_var;
+
+        // Temp var for class created by a previous pass. See `Transformer::with_class_temp_bindings`.
+        // Not used for anonymous `export default class {}`, as its temp var becomes the class's name.
+        let mut adopted_temp_binding =
+            self.adopted_temp_bindings.remove(&class_scope_id).map(|symbol_id| {
+                let name = ctx.ast.atom(ctx.symbols().get_name(symbol_id));
+                BoundIdentifier::new(name, symbol_id)
+            });
+
         let outer_hoist_scope_id = ctx.current_hoist_scope_id();
+        let temp_var_prefix =
+            self.ctx.class_temp_var_prefix.as_deref().map(|prefix| ctx.ast.atom(prefix));
         let class_temp_binding = if need_temp_var {
-            let temp_binding = ClassBindings::create_temp_binding(
-                class_name_binding.as_ref(),
-                outer_hoist_scope_id,
-                ctx,
-            );
-            if is_declaration {
-                // Anonymous `export default class {}`. Set class name binding to temp var.
-                // Actual class name will be set to this later.
-                class_name_binding = Some(temp_binding.clone());
+            if let Some(temp_binding) = adopted_temp_binding.take_if(|_| !is_declaration) {
+                // Already declared by previous pass
+                temp_var_is_created = true;
+                Some(temp_binding)
+            } else if defer_temp_var {
+                None
             } else {
-                // Create temp var `var _Class;` statement.
-                // TODO(improve-on-babel): Inserting the temp var `var _Class` statement here is only
-                // to match Babel's output. It'd be simpler just to insert it at the end and get rid of
-                // `temp_var_is_created` that tracks whether it's done already or not.
-                self.ctx.var_declarations.insert_var(&temp_binding, ctx);
+                let temp_binding = ClassBindings::create_temp_binding(
+                    class_name_binding.as_ref(),
+                    temp_var_prefix,
+                    outer_hoist_scope_id,
+                    ctx,
+                );
+                if is_declaration {
+                    // Anonymous `export default class {}`. Set class name binding to temp var.
+                    // Actual class name will be set to this later.
+                    class_name_binding = Some(temp_binding.clone());
+                } else {
+                    // Create temp var `var _Class;` statement.
+                    // TODO(improve-on-babel): Inserting the temp var `var _Class` statement here is only
+                    // to match Babel's output. It'd be simpler just to insert it at the end and get rid of
+                    // `temp_var_is_created` that tracks whether it's done already or not.
+                    self.ctx.var_declarations.insert_var(&temp_binding, ctx);
+                }
+                Some(temp_binding)
             }
-            Some(temp_binding)
         } else {
             None
         };
@@ -218,21 +268,29 @@ impl<'a> ClassProperties<'a, '_> {
         });
 
         let static_private_fields_use_temp = !is_declaration;
-        let class_bindings = ClassBindings::new(
+        let mut class_bindings = ClassBindings::new(
             class_name_binding,
             class_temp_binding,
             class_brand_binding,
             outer_hoist_scope_id,
+            temp_var_prefix,
             static_private_fields_use_temp,
-            need_temp_var,
+            temp_var_is_created,
+            use_name_for_temp,
         );
+        class_bindings.adopted_temp = adopted_temp_binding;
 
         // Add entry to `classes_stack`
         self.classes_stack.push(ClassDetails {
+            scope_id: class_scope_id,
             is_declaration,
             is_transform_required: true,
             private_props: if private_props.is_empty() { None } else { Some(private_props) },
             bindings: class_bindings,
+            static_initializer_strictness: self
+                .static_initializer_strictness
+                .get(&class_scope_id)
+                .copied(),
         });
 
         // Exit if no instance properties (public or private)
@@ -407,13 +465,15 @@ impl<'a> ClassProperties<'a, '_> {
 
         // Insert temp var for class if required. Name class if required.
         let class_details = self.classes_stack.last_mut();
-        if let Some(temp_binding) = &class_details.bindings.temp {
+        let mut this_temp_assignment_index = 0;
+        if let Some(temp_binding) = class_details.static_temp_binding() {
             // Binding for class name is required
             if let Some(ident) = &class.id {
                 // Insert `var _Class` statement, if it wasn't already in entry phase
                 if !class_details.bindings.temp_var_is_created {
                     self.ctx.var_declarations.insert_var(temp_binding, ctx);
                 }
+                self.class_temp_bindings.insert(class.scope_id(), temp_binding.symbol_id);
 
                 // Insert `_Class = Class` after class.
                 // TODO(improve-on-babel): Could just insert `var _Class = Class;` after class,
@@ -423,6 +483,7 @@ impl<'a> ClassProperties<'a, '_> {
                 let expr = create_assignment(temp_binding, class_name, ctx);
                 let stmt = ctx.ast.statement_expression(SPAN, expr);
                 self.insert_after_stmts.insert(0, stmt);
+                this_temp_assignment_index = 1;
             } else {
                 // Class must be default export `export default class {}`, as all other class declarations
                 // always have a name. Set class name.
@@ -431,6 +492,18 @@ impl<'a> ClassProperties<'a, '_> {
             }
         }
 
+        // Insert `var _thisClass` and `_thisClass = Class` after class (after `_Class = Class`),
+        // if temp var for `this` is required.
+        // Class always has a name by now - `export default class {}` has been named above.
+        if let Some(this_binding) = &class_details.bindings.this_temp {
+            self.ctx.var_declarations.insert_var(this_binding, ctx);
+            let ident = class.id.as_ref().unwrap();
+            let class_name = BoundIdentifier::from_binding_ident(ident).create_read_expression(ctx);
+            let expr = create_assignment(this_binding, class_name, ctx);
+            let stmt = ctx.ast.statement_expression(SPAN, expr);
+            self.insert_after_stmts.insert(this_temp_assignment_index, stmt);
+        }
+
         // Insert statements before/after class
         let stmt_address = match ctx.parent() {
             parent @ (Ancestor::ExportDefaultDeclarationDeclaration(_)
@@ -492,6 +565,14 @@ impl<'a> ClassProperties<'a, '_> {
             }
         }
 
+        // Statements are inserted directly into the AST, rather than returned to the caller.
+        // They contain references and scopes which have already been registered in `SymbolTable` /
+        // `ScopeTree`, so they must end up in the AST for semantic data to remain correct.
+        // For class expressions, there are no statements - initializers become a sequence expression.
+        // Custom emit (e.g. wrapping for HMR) should be done by a later AST pass over the output.
+        // There is no callback invoked as each statement is generated either - transformer has no
+        // plugin API. Tooling which needs to index them can find them in that pass: they immediately
+        // follow the class declaration they originate from.
         if !self.insert_after_stmts.is_empty() {
             self.ctx
                 .statement_injector
@@ -555,6 +636,25 @@ impl<'a> ClassProperties<'a, '_> {
         // to before class
         self.transform_class_elements(class, ctx);
 
+        // If temp var for class was deferred (`inline_class_temp_var` option), and it'd only be used
+        // as 1st argument of calls which return it, nest the calls and don't create temp var.
+        // `x = class { static a = 1; static b = 2; }`
+        // -> `x = _defineProperty(_defineProperty(class {}, "a", 1), "b", 2)`
+        // Otherwise create temp var (if it wasn't already), and replace placeholders with it.
+        if self.inline_class_temp_var {
+            if self.current_class().bindings.temp.is_none() && self.can_inline_class_temp_var() {
+                let mut nested = ctx.ast.move_expression(expr);
+                for call in self.insert_after_exprs.drain(..) {
+                    let Expression::CallExpression(mut call) = call else { unreachable!() };
+                    call.arguments[0] = Argument::from(nested);
+                    nested = Expression::CallExpression(call);
+                }
+                *expr = nested;
+                return;
+            }
+            self.replace_class_temp_var_placeholders(ctx);
+        }
+
         // Insert expressions before / after class.
         // `C = class { [x()] = 1; static y = 2 };`
         // -> `C = (_x = x(), _Class = class C { constructor() { this[_x] = 1; } }, _Class.y = 2, _Class)`
@@ -582,7 +682,9 @@ impl<'a> ClassProperties<'a, '_> {
             return;
         }
 
-        expr_count += 1 + usize::from(class_details.bindings.temp.is_some());
+        expr_count += 1
+            + usize::from(class_details.bindings.temp.is_some())
+            + usize::from(class_details.bindings.this_temp.is_some());
 
         let mut exprs = ctx.ast.vec_with_capacity(expr_count);
 
@@ -663,11 +765,13 @@ impl<'a> ClassProperties<'a, '_> {
         exprs.extend(self.insert_before.drain(..));
 
         // Insert class + static property assignments + static blocks
-        if let Some(binding) = &class_details.bindings.temp {
+        if let Some(binding) = class_details.static_temp_binding() {
             // Insert `var _Class` statement, if it wasn't already in entry phase
             if !class_details.bindings.temp_var_is_created {
                 self.ctx.var_declarations.insert_var(binding, ctx);
             }
+            let Expression::ClassExpression(class) = expr else { unreachable!() };
+            self.class_temp_bindings.insert(class.scope_id(), binding.symbol_id);
 
             // `_Class = class {}`
             let class_expr = ctx.ast.move_expression(expr);
@@ -681,6 +785,12 @@ impl<'a> ClassProperties<'a, '_> {
             }
 
             exprs.push(assignment);
+            // `_thisClass = _Class`
+            if let Some(this_binding) = &class_details.bindings.this_temp {
+                self.ctx.var_declarations.insert_var(this_binding, ctx);
+                let value = binding.create_read_expression(ctx);
+                exprs.push(create_assignment(this_binding, value, ctx));
+            }
             // Add static property assignments + static blocks
             exprs.extend(self.insert_after_exprs.drain(..));
             // `_Class`
@@ -722,7 +832,33 @@ impl<'a> ClassProperties<'a, '_> {
     /// * Transform private methods and insert after class.
     /// * Extract computed key assignments and insert them before class.
     /// * Remove all properties, private methods and static blocks from class body.
+    ///
+    /// Class `id` is never removed or altered. References to class name in static initializers are
+    /// replaced with temp var, but the class itself keeps its name, so `Function.prototype.name`
+    /// is unchanged. `x = class C { static y = C.name; }` -> `(_C = class C {}, _C.y = _C.name, _C)`.
     fn transform_class_elements(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!(
+            "transform_class_elements",
+            start = class.span.start,
+            end = class.span.end
+        )
+        .entered();
+
+        self.class_returning_exprs_count = 0;
+
+        let class_name = class.id.as_ref().map(|id| id.name);
+
+        // If static properties are moved out of the class, static blocks must be too,
+        // or they'd run before static properties which precede them.
+        // `class C { static a = log(1); static { log(2); } }`
+        // -> `class C {}; C.a = log(1); log(2);`
+        let transform_static_blocks = self.transform_static_blocks
+            || (self.preserve_static_initialization_order
+                && class.body.body.iter().any(|element| {
+                    matches!(element, ClassElement::PropertyDefinition(prop) if prop.r#static)
+                }));
+
         let mut class_methods = vec![];
         class.body.body.retain_mut(|element| {
             match element {
@@ -735,7 +871,7 @@ impl<'a> ClassProperties<'a, '_> {
                     return false;
                 }
                 ClassElement::StaticBlock(block) => {
-                    if self.transform_static_blocks {
+                    if transform_static_blocks {
                         self.convert_static_block(block, ctx);
                         return false;
                     }
@@ -749,18 +885,104 @@ impl<'a> ClassProperties<'a, '_> {
                 }
                 ClassElement::AccessorProperty(_) | ClassElement::TSIndexSignature(_) => {
                     // TODO: Need to handle these?
+                    // Auto-accessors (`static accessor x = this.y;`) are not lowered to a private
+                    // backing field + getter/setter, so remain in class body. `this` in initializer
+                    // is not transformed, which is correct as it's still inside the class. But it's now
+                    // evaluated before static properties which preceded it, as they're moved after class.
                 }
             }
 
             true
         });
 
+        self.insert_pending_static_assignments(ctx);
+
+        Self::update_class_scope_direct_eval_flag(class, ctx);
+
+        debug_assert!(class.id.as_ref().map(|id| id.name) == class_name);
+
         // All methods are moved to after the class, but need to be before static properties
         // TODO(improve-on-babel): Insertion order doesn't matter, and it more clear to insert according to
         // definition order.
         self.insert_after_stmts.splice(0..0, class_methods);
     }
 
+    /// Create placeholder for class temp var, to use as assignee of a static property.
+    ///
+    /// Used for class expressions when `inline_class_temp_var` option is enabled, and temp var
+    /// hasn't been created yet. Whether it's needed is decided in exit phase, once all static
+    /// properties have been transformed. Placeholders are then either replaced by the class itself
+    /// (calls are nested), or by references to temp var.
+    /// See `replace_class_temp_var_placeholders`.
+    ///
+    /// Placeholder is an `IdentifierReference` with no `ReferenceId`, so it's not recorded
+    /// in `SymbolTable` or `ScopeTree`.
+    pub(super) fn create_class_temp_var_placeholder(ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        ctx.ast.expression_identifier(SPAN, Atom::empty())
+    }
+
+    /// Check if expression is a placeholder created by `create_class_temp_var_placeholder`.
+    pub(super) fn is_class_temp_var_placeholder(expr: &Expression<'a>) -> bool {
+        matches!(expr, Expression::Identifier(ident) if ident.reference_id.get().is_none())
+    }
+
+    /// Get placeholder for class temp var in an expression to be inserted after class, if it has one.
+    ///
+    /// Placeholder can only be the assignee of a static property:
+    /// * 1st argument of `_defineProperty(<placeholder>, "x", 1)` or `Object.assign(<placeholder>, {})`.
+    /// * Object of assignment target `<placeholder>.x = 1` or `<placeholder>[_x] = 1` (`loose` option).
+    fn class_temp_var_placeholder_mut<'e>(
+        expr: &'e mut Expression<'a>,
+    ) -> Option<&'e mut Expression<'a>> {
+        let object = match expr {
+            Expression::CallExpression(call) => call.arguments.first_mut()?.as_expression_mut()?,
+            Expression::AssignmentExpression(assign) => match &mut assign.left {
+                AssignmentTarget::StaticMemberExpression(member) => &mut member.object,
+                AssignmentTarget::ComputedMemberExpression(member) => &mut member.object,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        Self::is_class_temp_var_placeholder(object).then_some(object)
+    }
+
+    /// Check if temp var for class expression can be replaced by nesting calls which define
+    /// static properties.
+    ///
+    /// Requires that temp var hasn't been created (nothing in class referenced it), and every
+    /// expression to be inserted after class is a call which returns the class it's passed
+    /// (`_defineProperty(<placeholder>, "x", 1)` or `Object.assign(<placeholder>, { x: 1 })`),
+    /// with a placeholder for temp var as its 1st argument. Nothing can be inserted before class.
+    fn can_inline_class_temp_var(&self) -> bool {
+        // Static blocks can produce calls with class as 1st argument (`static { f(this); }`),
+        // but they don't return the class
+        !self.insert_after_exprs.is_empty()
+            && self.class_returning_exprs_count == self.insert_after_exprs.len()
+            && self.insert_before.is_empty()
+            && self.insert_after_stmts.is_empty()
+            && self.current_class().private_props.is_none()
+            && self.insert_after_exprs.iter().all(|expr| {
+                matches!(
+                    expr,
+                    Expression::CallExpression(call)
+                        if call.arguments.first().and_then(Argument::as_expression)
+                            .is_some_and(Self::is_class_temp_var_placeholder)
+                )
+            })
+    }
+
+    /// Replace placeholders for class temp var in expressions to be inserted after class
+    /// with references to temp var. Temp var is created if it doesn't exist already.
+    fn replace_class_temp_var_placeholders(&mut self, ctx: &mut TraverseCtx<'a>) {
+        for expr in &mut self.insert_after_exprs {
+            if let Some(placeholder) = Self::class_temp_var_placeholder_mut(expr) {
+                let class_details = self.classes_stack.last_mut();
+                let binding = class_details.bindings.get_or_init_static_binding(ctx);
+                *placeholder = binding.create_read_expression(ctx);
+            }
+        }
+    }
+
     /// Flag that static private fields should be transpiled using temp binding,
     /// while in this static property or static block.
     ///
@@ -812,16 +1034,84 @@ impl<'a> ClassProperties<'a, '_> {
         )
     }
 
+    /// Check if class name binding is never reassigned, and is not exported from the module.
+    ///
+    /// If so, class name can be used instead of temp var for class, because the binding is guaranteed
+    /// to still refer to the class whenever code in static property initializers or static blocks runs.
+    ///
+    /// Exported classes are treated conservatively and always use a temp var.
+    ///
+    /// A direct `eval` anywhere the binding is visible (including in a static initializer) could
+    /// reassign it, without that showing up as a write reference. `DirectEval` flag propagates up
+    /// to all ancestor scopes, so checking the scope the binding is declared in covers all of them.
+    /// `class C { static x = eval("C = 1"); }`
+    fn class_name_is_unmutated(symbol_id: SymbolId, ctx: &TraverseCtx<'a>) -> bool {
+        let scope_id = ctx.symbols().get_scope_id(symbol_id);
+        !ctx.symbol_is_exported(symbol_id)
+            && !ctx.symbols().symbol_is_mutated(symbol_id)
+            && !ctx.scopes().get_flags(scope_id).contains_direct_eval()
+    }
+
+    /// Remove `DirectEval` flag from class scope if no direct `eval` remains in the class.
+    ///
+    /// Static property initializers and static blocks containing `eval` may have been moved out
+    /// of the class. Flag remains on the scopes `eval` is still within.
+    /// `class C { static x = eval("C = 1"); }` -> `class C {} C.x = eval("C = 1");`
+    fn update_class_scope_direct_eval_flag(class: &Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = class.scope_id();
+        if !ctx.scopes().get_flags(scope_id).contains_direct_eval() {
+            return;
+        }
+
+        let mut finder = DirectEvalFinder { found: false };
+        if let Some(super_class) = &class.super_class {
+            finder.visit_expression(super_class);
+        }
+        finder.visit_class_body(&class.body);
+        if !finder.found {
+            ctx.scopes_mut().get_flags_mut(scope_id).remove(ScopeFlags::DirectEval);
+        }
+    }
+
     /// Insert an expression after the class.
-    pub(super) fn insert_expr_after_class(&mut self, expr: Expression<'a>, ctx: &TraverseCtx<'a>) {
+    ///
+    /// Statement takes span of the expression, so comments attached to the original class element
+    /// (leading comments to its start, trailing comments to its end) stay with the statement.
+    pub(super) fn insert_expr_after_class(
+        &mut self,
+        expr: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // Any static property assignments which were held back to be combined go first
+        self.insert_pending_static_assignments(ctx);
+        self.push_expr_after_class(expr, ctx);
+    }
+
+    /// Insert an expression after the class, without inserting pending static property assignments first.
+    pub(super) fn push_expr_after_class(&mut self, expr: Expression<'a>, ctx: &TraverseCtx<'a>) {
         if self.current_class().is_declaration {
-            self.insert_after_stmts.push(ctx.ast.statement_expression(SPAN, expr));
+            self.insert_after_stmts.push(ctx.ast.statement_expression(expr.span(), expr));
         } else {
             self.insert_after_exprs.push(expr);
         }
     }
 }
 
+/// Visitor which checks if AST contains a direct `eval` call.
+struct DirectEvalFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for DirectEvalFinder {
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        if !call.optional && call.callee.is_specific_id("eval") {
+            self.found = true;
+            return;
+        }
+        walk::walk_call_expression(self, call);
+    }
+}
+
 /// Create `new WeakMap()` expression.
 ///
 /// Takes an `&mut Option<Option<SymbolId>>` which is updated after looking up the binding for `WeakMap`.
@@ -1,3 +1,4 @@
+use oxc_span::Atom;
 use oxc_syntax::{
     scope::ScopeId,
     symbol::{SymbolFlags, SymbolId},
@@ -44,34 +45,52 @@ pub(super) struct ClassBindings<'a> {
     /// Temp var for class.
     /// e.g. `_Class` in `_Class = class {}, _Class.x = 1, _Class`
     pub temp: Option<BoundIdentifier<'a>>,
+    /// Temp var for `this` in static initializers, if `separate_temp_var_for_this` option is enabled.
+    /// e.g. `_thisClass` in `_Class = class {}, _thisClass = _Class, _Class.x = _thisClass, _Class`
+    pub this_temp: Option<BoundIdentifier<'a>>,
     /// Temp var for WeakSet.
     pub brand: Option<BoundIdentifier<'a>>,
+    /// Temp var for class created by a previous pass, to use instead of creating a new temp var.
+    /// Taken by [`ClassBindings::get_or_init_static_binding`] if temp var turns out to be required.
+    pub adopted_temp: Option<BoundIdentifier<'a>>,
     /// `ScopeId` of hoist scope outside class (which temp `var` binding would be created in)
     pub outer_hoist_scope_id: ScopeId,
+    /// Custom prefix for name of temp var for class
+    pub temp_var_prefix: Option<Atom<'a>>,
     /// `true` if should use temp binding for references to class in transpiled static private fields,
     /// `false` if can use name binding
     pub static_private_fields_use_temp: bool,
     /// `true` if temp var for class has been inserted
     pub temp_var_is_created: bool,
+    /// `true` if name binding can be used in place of temp var, because class is a declaration
+    /// whose name binding is never reassigned (and class is not exported)
+    pub use_name_for_temp: bool,
 }
 
 impl<'a> ClassBindings<'a> {
     /// Create new `ClassBindings`.
+    #[expect(clippy::too_many_arguments)]
     pub fn new(
         name_binding: Option<BoundIdentifier<'a>>,
         temp_binding: Option<BoundIdentifier<'a>>,
         brand_binding: Option<BoundIdentifier<'a>>,
         outer_scope_id: ScopeId,
+        temp_var_prefix: Option<Atom<'a>>,
         static_private_fields_use_temp: bool,
         temp_var_is_created: bool,
+        use_name_for_temp: bool,
     ) -> Self {
         Self {
             name: name_binding,
             temp: temp_binding,
+            this_temp: None,
             brand: brand_binding,
+            adopted_temp: None,
             outer_hoist_scope_id: outer_scope_id,
+            temp_var_prefix,
             static_private_fields_use_temp,
             temp_var_is_created,
+            use_name_for_temp,
         }
     }
 
@@ -79,7 +98,7 @@ impl<'a> ClassBindings<'a> {
     ///
     /// Used when class needs no transform, and for dummy entry at top of `ClassesStack`.
     pub fn dummy() -> Self {
-        Self::new(None, None, None, ScopeId::new(0), false, false)
+        Self::new(None, None, None, ScopeId::new(0), None, false, false, false)
     }
 
     /// Get `SymbolId` of name binding.
@@ -110,34 +129,82 @@ impl<'a> ClassBindings<'a> {
     /// `static_private_fields_use_temp` is set accordingly at the right moments
     /// elsewhere in this transform.
     ///
+    /// If `use_name_for_temp` is `true`, name binding is always used, and temp binding is never created.
+    ///
     /// If a temp binding is required, and one doesn't already exist, a temp binding is created.
+    /// If a previous pass provided a temp binding for the class (`adopted_temp`), that binding is used
+    /// instead of creating a new one. It's already declared, so `temp_var_is_created` is set.
     pub fn get_or_init_static_binding(
         &mut self,
         ctx: &mut TraverseCtx<'a>,
     ) -> &BoundIdentifier<'a> {
-        if self.static_private_fields_use_temp {
-            // Create temp binding if doesn't already exist
-            self.temp.get_or_insert_with(|| {
-                Self::create_temp_binding(self.name.as_ref(), self.outer_hoist_scope_id, ctx)
-            })
+        if self.static_private_fields_use_temp && !self.use_name_for_temp {
+            // Adopt temp binding from previous pass, or create temp binding, if doesn't already exist
+            if self.temp.is_none() {
+                let temp_binding = if let Some(adopted_binding) = self.adopted_temp.take() {
+                    self.temp_var_is_created = true;
+                    adopted_binding
+                } else {
+                    Self::create_temp_binding(
+                        self.name.as_ref(),
+                        self.temp_var_prefix,
+                        self.outer_hoist_scope_id,
+                        ctx,
+                    )
+                };
+                self.temp = Some(temp_binding);
+            }
+            self.temp.as_ref().unwrap()
         } else {
-            // `static_private_fields_use_temp` is always `true` for class expressions.
+            // `static_private_fields_use_temp` is always `true` for class expressions,
+            // and `use_name_for_temp` is only `true` for class declarations with a name.
             // Class declarations always have a name binding if they have any static props.
             // So `unwrap` here cannot panic.
             self.name.as_ref().unwrap()
         }
     }
 
+    /// Get binding for temp var which replaces `this` in static initializers,
+    /// when `separate_temp_var_for_this` option is enabled.
+    ///
+    /// If binding doesn't already exist, it's created.
+    pub fn get_or_init_this_binding(&mut self, ctx: &mut TraverseCtx<'a>) -> &BoundIdentifier<'a> {
+        self.this_temp.get_or_insert_with(|| {
+            // Name binding is temp var `_Class` for `export default class {}`, so trim leading `_`s
+            let name = self
+                .name
+                .as_ref()
+                .map_or("Class", |binding| binding.name.as_str().trim_start_matches('_'));
+            ctx.generate_uid(
+                &format!("this{name}"),
+                self.outer_hoist_scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            )
+        })
+    }
+
     /// Generate binding for temp var.
     pub fn create_temp_binding(
         name_binding: Option<&BoundIdentifier<'a>>,
+        temp_var_prefix: Option<Atom<'a>>,
         outer_hoist_scope_id: ScopeId,
         ctx: &mut TraverseCtx<'a>,
     ) -> BoundIdentifier<'a> {
         // Base temp binding name on class name, or "Class" if no name.
-        // TODO(improve-on-babel): If class name var isn't mutated, no need for temp var for
-        // class declaration. Can just use class binding.
         let name = name_binding.map_or("Class", |binding| binding.name.as_str());
+        // Add custom prefix, if there is one.
+        // `generate_uid` makes sure the name doesn't clash with any existing binding, or any
+        // unresolved (global) reference anywhere in the program, so can't shadow a global
+        // which the class's initializers reference.
+        // e.g. `class C { static x = _C; static y = this; }` -> temp var is `_C2`.
+        if let Some(prefix) = temp_var_prefix {
+            let name = &format!("{prefix}{name}");
+            return ctx.generate_uid(
+                name,
+                outer_hoist_scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+        }
         ctx.generate_uid(name, outer_hoist_scope_id, SymbolFlags::FunctionScopedVariable)
     }
 }
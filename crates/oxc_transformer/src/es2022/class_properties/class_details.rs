@@ -1,6 +1,7 @@
 use oxc_ast::ast::*;
 use oxc_data_structures::stack::NonEmptyStack;
 use oxc_span::Atom;
+use oxc_syntax::scope::ScopeId;
 use oxc_traverse::BoundIdentifier;
 
 use super::{ClassBindings, ClassProperties, FxIndexMap};
@@ -9,6 +10,8 @@ use super::{ClassBindings, ClassProperties, FxIndexMap};
 ///
 /// These are stored in `ClassesStack`.
 pub(super) struct ClassDetails<'a> {
+    /// `ScopeId` of class. Root scope for dummy entry.
+    pub scope_id: ScopeId,
     /// `true` for class declaration, `false` for class expression
     pub is_declaration: bool,
     /// `true` if class requires no transformation
@@ -20,20 +23,34 @@ pub(super) struct ClassDetails<'a> {
     pub private_props: Option<FxIndexMap<Atom<'a>, PrivateProp<'a>>>,
     /// Bindings for class name and temp var for class
     pub bindings: ClassBindings<'a>,
+    /// Strictness of code outside class provided by caller, used for static property initializers
+    /// instead of flags of current scope. See `Transformer::with_static_initializer_strictness`.
+    pub static_initializer_strictness: Option<bool>,
 }
 
-impl ClassDetails<'_> {
+impl<'a> ClassDetails<'a> {
     /// Create dummy `ClassDetails`.
     ///
     /// Used for dummy entry at top of `ClassesStack`.
     pub fn dummy(is_declaration: bool) -> Self {
         Self {
+            scope_id: ScopeId::new(0),
             is_declaration,
             is_transform_required: false,
             private_props: None,
             bindings: ClassBindings::dummy(),
+            static_initializer_strictness: None,
         }
     }
+
+    /// Get temp var for class (`_Class`), if one has been created.
+    ///
+    /// Class's entry in `ClassesStack` is removed when exiting the class, so this is only available
+    /// during transform of the class. After transform, temp vars are available from
+    /// `TransformerReturn::class_temp_bindings`.
+    pub fn static_temp_binding(&self) -> Option<&BoundIdentifier<'a>> {
+        self.bindings.temp.as_ref()
+    }
 }
 
 /// Details of a private property.
@@ -92,6 +109,12 @@ impl<'a> ClassesStack<'a> {
         Self { stack: NonEmptyStack::new(ClassDetails::dummy(false)) }
     }
 
+    /// Returns `true` if stack contains no classes (only the dummy entry).
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.stack.len() == 1
+    }
+
     /// Push an entry to stack.
     #[inline]
     pub fn push(&mut self, class: ClassDetails<'a>) {
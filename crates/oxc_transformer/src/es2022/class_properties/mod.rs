@@ -155,6 +155,48 @@
 //! fields in the `extends` clause being incorrectly resolved to private properties defined in that class,
 //! as `extends` clause is visited before class body.
 //!
+//! ### Interaction with legacy decorators
+//!
+//! Legacy decorators transform runs on exiting the class declaration *statement*, which is after
+//! this transform's exit phase. So static properties have already been moved out of the class
+//! by the time decorators are applied. Legacy decorators transform replaces the class declaration
+//! with `let C = class C {}`, and carries over the statements this transform inserted after the class,
+//! so static properties are initialized before decorators are applied, same as TypeScript:
+//!
+//! ```js
+//! let C = class C {};
+//! _C = C;
+//! babelHelpers.defineProperty(C, "x", _C);
+//! C = babelHelpers.decorate([dec], C);
+//! ```
+//!
+//! `this` in static property initializers is the undecorated class, even if a decorator returns
+//! a new class.
+//!
+//! Note: Decorators on properties are not currently applied when this transform is enabled,
+//! because properties are removed from the class before legacy decorators transform runs.
+//!
+//! ### Idempotency
+//!
+//! Running the transform again on its own output (after printing and re-parsing it) produces
+//! identical output. All static properties are moved out of the class in 1st pass, and all
+//! references to `this` and class name within them are replaced with temp vars (or class name,
+//! where that's safe), which are ordinary variables by the time of 2nd pass.
+//!
+//! This does not apply to running [`ClassProperties::transform_static_initializer`] twice on the
+//! same initializer, without the class being transformed in between. e.g. with
+//! `strict_mode_iife_for_static_initializers` option, the IIFE would be wrapped in another IIFE.
+//!
+//! ### Private static properties
+//!
+//! Private static properties don't use `WeakMap`s. Each is stored in an object `var _foo = { _: 123 };`
+//! after the class, so there's one temp var and one object per property.
+//!
+//! Creating this storage lazily (on first access) is not supported. Initializers may have side effects,
+//! or read earlier static properties, so they must run in order at the point the class is defined,
+//! the same as public static properties. Only the initializer-less case (`static #foo;`) could be
+//! deferred, and `{ _: void 0 }` is cheap anyway. See `transformer_private_static_fields` benchmark.
+//!
 //! ### Structures
 //!
 //! Transform stores 2 sets of state:
@@ -188,6 +230,7 @@
 //! * `class_details.rs`:              Structures containing details of classes and private properties.
 //! * `class_bindings.rs`:             Structure containing bindings for class name and temp var.
 //! * `utils.rs`:                      Utility functions.
+//! * `stats.rs`:                      Traversal statistics (only with `transform_stats` feature).
 //!
 //! ## References
 //!
@@ -202,7 +245,7 @@ use rustc_hash::{FxBuildHasher, FxHashMap};
 use serde::Deserialize;
 
 use oxc_ast::ast::*;
-use oxc_span::Atom;
+use oxc_span::{Atom, Span};
 use oxc_syntax::{scope::ScopeId, symbol::SymbolId};
 use oxc_traverse::{Traverse, TraverseCtx};
 
@@ -218,23 +261,142 @@ mod private_field;
 mod private_method;
 mod prop_decl;
 mod static_block_and_prop_init;
+#[cfg(feature = "transform_stats")]
+mod stats;
 mod super_converter;
 mod utils;
 use class_bindings::ClassBindings;
 use class_details::{ClassDetails, ClassesStack, PrivateProp, ResolvedPrivateProp};
+#[cfg(feature = "transform_stats")]
+pub use stats::ClassPropertiesStats;
 
 type FxIndexMap<K, V> = IndexMap<K, V, FxBuildHasher>;
 
+/// Callback to replace `this` in static property initializers and static blocks.
+///
+/// Used instead of replacing `this` with class temp var.
+/// Useful for mixin composition, where the "class" at runtime is a wrapper around the class.
+///
+/// Called with:
+/// 1. `ScopeId` of the class.
+/// 2. `ScopeId` of the scope containing `this`.
+/// 3. `Span` of `this`.
+///
+/// Return `Some(expr)` to replace `this` with `expr`, or `None` to replace it with class temp var
+/// as usual. e.g. returning `mixinTarget`: `class C { static x = this; }` -> `C.x = mixinTarget;`
+///
+/// Contract:
+/// * Only called for `this` which would otherwise be replaced with class temp var.
+///   `this` in nested functions, and static properties which remain in the class, are left as is.
+/// * Returned expression is not transformed further. It must not contain `this` or `super`.
+/// * Caller is responsible for scoping. Any `IdentifierReference` in returned expression must have
+///   a `Reference` created for it, resolved from the scope containing `this`
+///   (e.g. with `TraverseCtx::create_ident_expr`). Any scopes in it must be created as children of
+///   that scope. `TraverseCtx::current_scope_id` is not the scope containing `this`.
+/// * Expression is evaluated where `this` was, after class has been created.
+///   Nothing in it is declared by the transform, so any bindings it references must be in scope at runtime.
+/// * References to class name are unaffected, and still use class name or temp var.
+pub type StaticThisReplacement<'a> =
+    Box<dyn Fn(ScopeId, ScopeId, Span, &mut TraverseCtx<'a>) -> Option<Expression<'a>> + 'a>;
+
 #[derive(Debug, Default, Clone, Copy, Deserialize)]
 #[serde(default, rename_all = "camelCase")]
 pub struct ClassPropertiesOptions {
     pub(crate) loose: bool,
+    /// If `true`, do not create a temp var for class declarations whose name binding is never
+    /// reassigned. `this` and references to class name in static initializers use class name instead.
+    pub(crate) skip_temp_var_for_unmutated_class: bool,
+    /// If `true`, static property initializers which are moved outside the class into sloppy mode code
+    /// are wrapped in a strict mode IIFE `(() => { "use strict"; return init; })()`,
+    /// so they still run in strict mode, as they did in the class body.
+    pub(crate) strict_mode_iife_for_static_initializers: bool,
+    /// If `true`, static blocks in classes which have static properties are transformed, even if
+    /// static blocks transform is not enabled. Static properties and static blocks are then
+    /// evaluated in source order, rather than static blocks (left in class) running first.
+    pub(crate) preserve_static_initialization_order: bool,
+    /// If `true`, `delete this` in static property initializers and static blocks keeps the `delete`,
+    /// with `this` replaced by temp var (`delete (0, _C)`), instead of being replaced with `true`.
+    /// Temp var is wrapped in a sequence because `delete _C` is a syntax error in strict mode.
+    pub(crate) preserve_delete_this: bool,
+    /// If `true`, `this` in static property initializers and static blocks is replaced with a separate
+    /// temp var `_thisC` (`_thisClass` for anonymous classes), instead of the class temp var used for
+    /// references to class name. This makes it visible in output which references were originally `this`.
+    /// `class C { static x = this; static y = C; }`
+    /// -> `var _thisC, _C; class C {} _C = C; _thisC = C; C.x = _thisC; C.y = _C;`
+    ///
+    /// Both temp vars are assigned the class, so they always refer to the same value.
+    /// Not used for `this` which is replaced by [`StaticThisReplacement`] callback.
+    pub(crate) separate_temp_var_for_this: bool,
+    /// Maximum nesting depth of expressions in a static property initializer or static block.
+    /// Beyond this depth, an error is raised and the rest of the expression is not transformed,
+    /// rather than risking a stack overflow.
+    ///
+    /// Defaults to [`DEFAULT_MAX_STATIC_INITIALIZER_DEPTH`], which is far deeper than any realistic code.
+    pub(crate) max_static_initializer_depth: Option<u32>,
+    /// If `true`, and static blocks are not being transformed, public static properties are converted
+    /// to static blocks inside the class, instead of being moved to after the class.
+    /// `class C { static x = this; }` -> `class C { static { _defineProperty(this, "x", this); } }`
+    /// Initializers stay inside the class, so `this`, `super` and class name don't need transforming.
+    ///
+    /// Not applied to classes which have private properties or private methods, or static properties
+    /// with computed keys. These classes are transformed as usual.
+    pub(crate) static_props_in_static_blocks: bool,
+    /// If `true`, and `loose` option or `setPublicClassFields` assumption is enabled, runs of
+    /// consecutive static properties whose initializers are literals are set with a single
+    /// `Object.assign` call, instead of an assignment for each.
+    /// `class C { static a = 1; static b = "x"; static c = f(); }`
+    /// -> `class C {} Object.assign(C, { a: 1, b: "x" }); C.c = f();`
+    ///
+    /// `Object.assign` sets properties with `[[Set]]` in order, same as separate assignments.
+    /// Only literal initializers (and `static x;`) are combined, so no initializer is evaluated earlier
+    /// than it would be otherwise. Any other static property or static block ends the run.
+    /// Properties with computed or non-identifier keys, or named `__proto__`, are not combined,
+    /// and nor are properties with the same name as a previous property in the run.
+    /// Not applied if `Object` is shadowed by a local binding.
+    pub(crate) combine_static_property_assignments: bool,
+    /// If `true`, class expressions whose temp var is only used to define static properties
+    /// don't create a temp var. Calls which define the properties are nested instead, as
+    /// `_defineProperty` and `Object.assign` both return the object they're passed.
+    /// `x = class { static a = 1; static b = 2; }`
+    /// -> `x = _defineProperty(_defineProperty(class {}, "a", 1), "b", 2);`
+    /// instead of `x = (_Class = class {}, _defineProperty(_Class, "a", 1), _defineProperty(_Class, "b", 2), _Class);`
+    ///
+    /// Not applied if temp var is referenced anywhere else (e.g. `this` in an initializer),
+    /// or class has static blocks, private properties, private methods or computed keys.
+    /// Properties set with assignments (`loose` option) can't be nested, so are not inlined,
+    /// unless they're combined into `Object.assign` by `combine_static_property_assignments` option.
+    pub(crate) inline_class_temp_var: bool,
+    /// If `true`, TypeScript types in static property initializers and static blocks are not visited
+    /// when transforming them. Types can't contain `this` or `super` expressions which need
+    /// transforming, but visiting them is required to keep scope tree correct for types which
+    /// have their own scope (e.g. `TSConditionalType`, `TSMappedType`).
+    ///
+    /// Only use this if types are guaranteed to be erased before class properties transform runs.
+    /// This is the case when TypeScript transform is enabled, as it removes types before classes are
+    /// transformed, so usually there are no types left to visit anyway.
+    ///
+    /// Off by default, so scopes of any types which remain are still updated correctly.
+    pub(crate) skip_ts_types_in_static_initializers: bool,
+    /// If `true`, references to class name in static property initializers and static blocks
+    /// of class declarations are left as is, instead of being replaced with class temp var.
+    /// `this` and `super` are still transformed.
+    /// `class C { static x = [this, C]; }` -> `var _C; class C {} _C = C; C.x = [_C, C];`
+    ///
+    /// For use when class name references have already been dealt with by another tool.
+    /// Not applied to class expressions, as class expression's name is only in scope inside the class.
+    pub(crate) preserve_class_name_references: bool,
 }
 
+/// Default for [`ClassPropertiesOptions::max_static_initializer_depth`].
+const DEFAULT_MAX_STATIC_INITIALIZER_DEPTH: u32 = 1000;
+
 /// Class properties transform.
 ///
 /// See [module docs] for details.
 ///
+/// Only runs as part of a full AST traversal, as it depends on traversal's ancestry stack.
+/// For static analysis of a class without transforming it, see `oxc_semantic::ClassStaticReferences`.
+///
 /// [module docs]: self
 pub struct ClassProperties<'a, 'ctx> {
     // ----- Options -----
@@ -245,6 +407,30 @@ pub struct ClassProperties<'a, 'ctx> {
     private_fields_as_properties: bool,
     /// If `true`, transform static blocks.
     transform_static_blocks: bool,
+    /// If `true`, use class name instead of temp var for class declarations which are never reassigned.
+    skip_temp_var_for_unmutated_class: bool,
+    /// If `true`, wrap static property initializers in a strict mode IIFE if outer code is sloppy mode.
+    strict_mode_iife_for_static_initializers: bool,
+    /// If `true`, transform static blocks in classes with static properties, to preserve evaluation order.
+    preserve_static_initialization_order: bool,
+    /// If `true`, keep `delete` of `delete this` in static initializers, instead of replacing with `true`.
+    preserve_delete_this: bool,
+    /// Callback to replace `this` in static initializers. See [`StaticThisReplacement`].
+    static_this_replacement: Option<StaticThisReplacement<'a>>,
+    /// If `true`, replace `this` in static initializers with a separate temp var from class name.
+    separate_temp_var_for_this: bool,
+    /// Maximum nesting depth of expressions in static initializers.
+    max_static_initializer_depth: u32,
+    /// If `true`, convert static properties to static blocks, where possible.
+    static_props_in_static_blocks: bool,
+    /// If `true`, combine consecutive literal static property assignments into `Object.assign` call.
+    combine_static_property_assignments: bool,
+    /// If `true`, avoid temp var for class expressions where it's only used to define static properties.
+    inline_class_temp_var: bool,
+    /// If `true`, don't visit TypeScript types in static initializers.
+    skip_ts_types_in_static_initializers: bool,
+    /// If `true`, don't replace class name references in static initializers of class declarations.
+    preserve_class_name_references: bool,
 
     ctx: &'ctx TransformCtx<'a>,
 
@@ -261,6 +447,16 @@ pub struct ClassProperties<'a, 'ctx> {
     /// This problem only affects class expressions. Class declarations aren't affected,
     /// as their exit-phase transform happens in `exit_class`.
     classes_stack: ClassesStack<'a>,
+    /// Temp vars for classes created by a previous pass, keyed by class's `ScopeId`.
+    /// Used instead of creating new temp vars. Entries are removed as classes are entered.
+    adopted_temp_bindings: FxHashMap<ScopeId, SymbolId>,
+    /// Temp vars created for classes (or adopted from a previous pass), keyed by class's `ScopeId`.
+    /// Only includes temp vars which remain in the output.
+    /// Returned in `TransformerReturn::class_temp_bindings`.
+    class_temp_bindings: FxHashMap<ScopeId, SymbolId>,
+    /// Strictness of code outside classes provided by caller, keyed by class's `ScopeId`.
+    /// See [`ClassProperties::set_static_initializer_strictness`].
+    static_initializer_strictness: FxHashMap<ScopeId, bool>,
 
     // ----- State used only during enter phase -----
     //
@@ -286,6 +482,13 @@ pub struct ClassProperties<'a, 'ctx> {
     insert_after_exprs: Vec<Expression<'a>>,
     /// Statements to insert after class declaration
     insert_after_stmts: Vec<Statement<'a>>,
+    /// Static property assignments which may be combined into an `Object.assign` call.
+    /// Only used if `combine_static_property_assignments` option is enabled.
+    pending_static_assignments: Vec<Expression<'a>>,
+    /// Number of expressions in `insert_after_exprs` which return the class they're passed
+    /// (`_defineProperty(C, "x", 1)` or `Object.assign(C, { x: 1 })`).
+    /// Only used if `inline_class_temp_var` option is enabled.
+    class_returning_exprs_count: usize,
 }
 
 impl<'a, 'ctx> ClassProperties<'a, 'ctx> {
@@ -305,8 +508,26 @@ impl<'a, 'ctx> ClassProperties<'a, 'ctx> {
             set_public_class_fields,
             private_fields_as_properties,
             transform_static_blocks,
+            skip_temp_var_for_unmutated_class: options.skip_temp_var_for_unmutated_class,
+            strict_mode_iife_for_static_initializers: options
+                .strict_mode_iife_for_static_initializers,
+            preserve_static_initialization_order: options.preserve_static_initialization_order,
+            preserve_delete_this: options.preserve_delete_this,
+            static_this_replacement: None,
+            separate_temp_var_for_this: options.separate_temp_var_for_this,
+            max_static_initializer_depth: options
+                .max_static_initializer_depth
+                .unwrap_or(DEFAULT_MAX_STATIC_INITIALIZER_DEPTH),
+            static_props_in_static_blocks: options.static_props_in_static_blocks,
+            combine_static_property_assignments: options.combine_static_property_assignments,
+            inline_class_temp_var: options.inline_class_temp_var,
+            skip_ts_types_in_static_initializers: options.skip_ts_types_in_static_initializers,
+            preserve_class_name_references: options.preserve_class_name_references,
             ctx,
             classes_stack: ClassesStack::new(),
+            adopted_temp_bindings: FxHashMap::default(),
+            class_temp_bindings: FxHashMap::default(),
+            static_initializer_strictness: FxHashMap::default(),
             // Temporary values - overwritten when entering class
             instance_inits_scope_id: ScopeId::new(0),
             instance_inits_constructor_scope_id: None,
@@ -315,11 +536,53 @@ impl<'a, 'ctx> ClassProperties<'a, 'ctx> {
             insert_before: vec![],
             insert_after_exprs: vec![],
             insert_after_stmts: vec![],
+            pending_static_assignments: vec![],
+            class_returning_exprs_count: 0,
         }
     }
+
+    /// Use temp vars created by a previous pass for classes, instead of creating new ones.
+    ///
+    /// `bindings` maps `ScopeId` of a class to `SymbolId` of a temp var for that class.
+    /// If the class requires a temp var, that binding is used, and no `var` declaration is inserted
+    /// for it, as the previous pass must already have declared it.
+    /// e.g. `var _C; class C { static x = this; }` -> `var _C; class C {} _C = C; C.x = _C;`
+    /// rather than creating `_C2` as well.
+    pub fn adopt_temp_bindings(&mut self, bindings: FxHashMap<ScopeId, SymbolId>) {
+        self.adopted_temp_bindings.extend(bindings);
+    }
+
+    /// Provide strictness of code outside classes, keyed by `ScopeId` of the class.
+    ///
+    /// Static property initializers of these classes are transformed with
+    /// [`ClassProperties::transform_static_initializer_with_strictness`], using the provided
+    /// strictness instead of flags of the scope outside the class. Static blocks are unaffected.
+    pub fn set_static_initializer_strictness(&mut self, strictness: FxHashMap<ScopeId, bool>) {
+        self.static_initializer_strictness.extend(strictness);
+    }
+
+    /// Provide callback to replace `this` in static property initializers and static blocks.
+    /// See [`StaticThisReplacement`].
+    pub fn set_static_this_replacement(&mut self, replacement: StaticThisReplacement<'a>) {
+        self.static_this_replacement = Some(replacement);
+    }
+
+    /// Take temp vars used for classes, keyed by `ScopeId` of the class.
+    ///
+    /// Only classes which were transformed and use a temp var in the output are included.
+    /// Temp var which becomes the name of an anonymous `export default class {}` is not included.
+    pub fn take_temp_bindings(&mut self) -> FxHashMap<ScopeId, SymbolId> {
+        std::mem::take(&mut self.class_temp_bindings)
+    }
 }
 
 impl<'a> Traverse<'a> for ClassProperties<'a, '_> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.skip_temp_var_for_unmutated_class {
+            ctx.collect_exported_symbols(program);
+        }
+    }
+
     fn enter_class_body(&mut self, body: &mut ClassBody<'a>, ctx: &mut TraverseCtx<'a>) {
         self.transform_class_body_on_entry(body, ctx);
     }
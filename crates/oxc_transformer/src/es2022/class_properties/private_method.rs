@@ -137,6 +137,11 @@ impl<'a> VisitMut<'a> for PrivateMethodVisitor<'a, '_, '_> {
                 self.super_converter
                     .transform_call_expression_for_super_member_expr(call_expr, self.ctx);
             }
+            // `super.prop?.()`
+            Expression::ChainExpression(chain_expr) => {
+                self.super_converter
+                    .transform_chain_expression_for_super_member_call(chain_expr, self.ctx);
+            }
             // `super.prop = value`, `super.prop += value`, `super.prop ??= value`
             Expression::AssignmentExpression(_) => {
                 self.super_converter
@@ -2,8 +2,8 @@
 //! Transform of class property declarations (instance or static properties).
 
 use oxc_ast::{NONE, ast::*};
-use oxc_span::SPAN;
-use oxc_syntax::reference::ReferenceFlags;
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{reference::ReferenceFlags, scope::ScopeFlags};
 use oxc_traverse::TraverseCtx;
 
 use crate::common::helper_loader::Helper;
@@ -91,6 +91,9 @@ impl<'a> ClassProperties<'a, '_> {
         // Get value.
         // Transform it to replace `this` and references to class name with temp var for class.
         // Also transform `super`.
+        // Computed key is not transformed here. It's evaluated outside class, where `this` and `super`
+        // are those of enclosing scope, not the class, so `this` and `super` in key remain as is.
+        // `static [Symbol.for(this.k)] = this.y` -> `_key = Symbol.for(this.k); ... _C.y`
         let value = match prop.value.take() {
             Some(mut value) => {
                 self.transform_static_initializer(&mut value, ctx);
@@ -104,18 +107,189 @@ impl<'a> ClassProperties<'a, '_> {
         } else {
             // Convert to assignment or `_defineProperty` call, depending on `loose` option
             let class_details = self.current_class();
-            let class_binding = if class_details.is_declaration {
+            let assignee = if class_details.is_declaration {
                 // Class declarations always have a name except `export default class {}`.
                 // For default export, binding is created when static prop found in 1st pass.
-                class_details.bindings.name.as_ref().unwrap()
+                class_details.bindings.name.as_ref().unwrap().create_read_expression(ctx)
+            } else if let Some(temp_binding) = &class_details.bindings.temp {
+                // Binding is created when static prop found in 1st pass
+                temp_binding.create_read_expression(ctx)
             } else {
-                // Binding is created when static prop found in 1st pass.
-                class_details.bindings.temp.as_ref().unwrap()
+                // Temp var is deferred, because `inline_class_temp_var` option is enabled
+                Self::create_class_temp_var_placeholder(ctx)
             };
 
-            let assignee = class_binding.create_read_expression(ctx);
             let init_expr = self.create_init_assignment(prop, value, assignee, true, ctx);
-            self.insert_expr_after_class(init_expr, ctx);
+            self.insert_static_assignment_after_class(init_expr, ctx);
+        }
+    }
+
+    /// Insert static property assignment after class.
+    ///
+    /// If `combine_static_property_assignments` option is enabled, and assignment can be combined
+    /// with others (`C.x = 1`, where value is a literal), hold it back in `pending_static_assignments`.
+    /// It's inserted when the next non-combinable expression is inserted after class, or at end of
+    /// class elements.
+    fn insert_static_assignment_after_class(
+        &mut self,
+        expr: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.combine_static_property_assignments {
+            if let Some(name) = Self::combinable_static_assignment_name(&expr) {
+                // `Object.assign(C, { a: 1, a: 2 })` would only set `a` once
+                let is_duplicate = self
+                    .pending_static_assignments
+                    .iter()
+                    .any(|expr| Self::combinable_static_assignment_name(expr) == Some(name));
+                if is_duplicate {
+                    self.insert_pending_static_assignments(ctx);
+                }
+                self.pending_static_assignments.push(expr);
+                return;
+            }
+        }
+
+        // `_defineProperty(C, "x", 1)` returns `C`. `C.x = 1` doesn't.
+        let returns_class = matches!(expr, Expression::CallExpression(_));
+        self.insert_expr_after_class(expr, ctx);
+        if returns_class {
+            self.class_returning_exprs_count += 1;
+        }
+    }
+
+    /// If expression is an assignment `C.x = <literal>` which can be combined into an `Object.assign`
+    /// call, return the property name.
+    ///
+    /// Key `__proto__` is excluded, because `{ __proto__: 1 }` sets prototype of the object literal,
+    /// rather than defining a property.
+    fn combinable_static_assignment_name(expr: &Expression<'a>) -> Option<Atom<'a>> {
+        let Expression::AssignmentExpression(assign) = expr else { return None };
+        let AssignmentTarget::StaticMemberExpression(member) = &assign.left else { return None };
+        let name = member.property.name;
+        let is_combinable =
+            (assign.right.is_literal() || assign.right.is_void_0()) && name != "__proto__";
+        is_combinable.then_some(name)
+    }
+
+    /// Insert static property assignments held back in `pending_static_assignments` after class.
+    ///
+    /// If there's more than 1, combine them into a single `Object.assign` call.
+    /// `C.a = 1; C.b = 2;` -> `Object.assign(C, { a: 1, b: 2 });`
+    pub(super) fn insert_pending_static_assignments(&mut self, ctx: &mut TraverseCtx<'a>) {
+        match self.pending_static_assignments.len() {
+            0 => return,
+            1 => {
+                let expr = self.pending_static_assignments.pop().unwrap();
+                self.push_expr_after_class(expr, ctx);
+                return;
+            }
+            _ => {}
+        }
+
+        // If `Object` is shadowed, can't use `Object.assign`. Insert assignments separately.
+        let object_symbol_id = ctx.scopes().find_binding(ctx.current_scope_id(), "Object");
+        if object_symbol_id.is_some() {
+            for expr in std::mem::take(&mut self.pending_static_assignments) {
+                self.push_expr_after_class(expr, ctx);
+            }
+            return;
+        }
+
+        // `{ a: 1, b: 2 }`
+        let mut assignee = None;
+        let properties =
+            ctx.ast.vec_from_iter(self.pending_static_assignments.drain(..).map(|expr| {
+                let Expression::AssignmentExpression(assign) = expr else { unreachable!() };
+                let assign = assign.unbox();
+                let AssignmentTarget::StaticMemberExpression(member) = assign.left else {
+                    unreachable!()
+                };
+                let member = member.unbox();
+                // Assignee is the same class binding in every assignment. Keep 1st one,
+                // and delete references for the rest.
+                if assignee.is_none() {
+                    assignee = Some(member.object);
+                } else if let Expression::Identifier(ident) = &member.object {
+                    if !Self::is_class_temp_var_placeholder(&member.object) {
+                        ctx.delete_reference_for_identifier(ident);
+                    }
+                }
+                ctx.ast.object_property_kind_object_property(
+                    assign.span,
+                    PropertyKind::Init,
+                    PropertyKey::StaticIdentifier(ctx.ast.alloc(member.property)),
+                    assign.right,
+                    false,
+                    false,
+                    false,
+                )
+            }));
+        let object = ctx.ast.expression_object(SPAN, properties, None);
+
+        // `Object.assign(C, { a: 1, b: 2 })`
+        let callee =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("Object"), ReferenceFlags::Read);
+        let property = ctx.ast.identifier_name(SPAN, "assign");
+        let callee =
+            Expression::from(ctx.ast.member_expression_static(SPAN, callee, property, false));
+        let arguments =
+            ctx.ast.vec_from_array([Argument::from(assignee.unwrap()), Argument::from(object)]);
+        let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        self.push_expr_after_class(call, ctx);
+        self.class_returning_exprs_count += 1;
+    }
+
+    /// Check if static properties of class can be converted to static blocks.
+    ///
+    /// Not possible if class has any private properties or methods, because transformed private
+    /// property accesses in static context reference class temp var, which isn't assigned until
+    /// after the class.
+    ///
+    /// Not possible for static properties with computed keys, because key must be evaluated
+    /// along with other keys, before any static initializers run.
+    pub(super) fn can_convert_static_props_to_static_blocks(body: &ClassBody<'a>) -> bool {
+        body.body.iter().all(|element| match element {
+            ClassElement::PropertyDefinition(prop) => {
+                !prop.key.is_private_identifier() && (!prop.r#static || !prop.computed)
+            }
+            ClassElement::MethodDefinition(method) => !method.key.is_private_identifier(),
+            ClassElement::AccessorProperty(prop) => !prop.key.is_private_identifier(),
+            ClassElement::StaticBlock(_) | ClassElement::TSIndexSignature(_) => true,
+        })
+    }
+
+    /// Convert static properties to static blocks.
+    ///
+    /// `static x = 123;` -> `static { _defineProperty(this, "x", 123); }`
+    /// or `static { this.x = 123; }` (loose).
+    ///
+    /// Each static block takes the place of the property it replaces, so evaluation order is unchanged.
+    /// Must be called on entering class body, when current scope is class scope.
+    pub(super) fn convert_static_props_to_static_blocks(
+        &mut self,
+        body: &mut ClassBody<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        for element in &mut body.body {
+            let ClassElement::PropertyDefinition(prop) = element else { continue };
+            if !prop.r#static {
+                continue;
+            }
+
+            let span = prop.span;
+            let value = prop.value.take().unwrap_or_else(|| ctx.ast.void_0(SPAN));
+            // Scopes in initializer become children of static block's scope
+            let scope_id = ctx.insert_scope_below_expression(&value, ScopeFlags::ClassStaticBlock);
+
+            let this = ctx.ast.expression_this(SPAN);
+            let init_expr = self.create_init_assignment(prop, value, this, true, ctx);
+            let stmt = ctx.ast.statement_expression(SPAN, init_expr);
+            *element = ClassElement::StaticBlock(ctx.ast.alloc_static_block_with_scope_id(
+                span,
+                ctx.ast.vec1(stmt),
+                scope_id,
+            ));
         }
     }
 
@@ -149,14 +323,15 @@ impl<'a> ClassProperties<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         // TODO: This logic appears elsewhere. De-duplicate it.
-        let class_details = self.current_class();
+        let class_details = self.current_class_mut();
         let class_binding = if class_details.is_declaration {
             // Class declarations always have a name except `export default class {}`.
             // For default export, binding is created when static prop found in 1st pass.
             class_details.bindings.name.as_ref().unwrap()
         } else {
-            // Binding is created when static prop found in 1st pass.
-            class_details.bindings.temp.as_ref().unwrap()
+            // Binding is created when static prop found in 1st pass,
+            // unless `inline_class_temp_var` option is enabled
+            class_details.bindings.get_or_init_static_binding(ctx)
         };
 
         let assignee = class_binding.create_read_expression(ctx);
@@ -262,9 +437,9 @@ impl<'a> ClassProperties<'a, '_> {
             }
         };
 
-        // TODO: Should this have span of the original `PropertyDefinition`?
+        // Use span of original `PropertyDefinition`, so source maps map the assignment back to it
         ctx.ast.expression_assignment(
-            SPAN,
+            prop.span,
             AssignmentOperator::Assign,
             AssignmentTarget::from(left),
             value,
@@ -303,8 +478,8 @@ impl<'a> ClassProperties<'a, '_> {
             Argument::from(key),
             Argument::from(value),
         ]);
-        // TODO: Should this have span of the original `PropertyDefinition`?
-        self.ctx.helper_call_expr(Helper::DefineProperty, SPAN, arguments, ctx)
+        // Use span of original `PropertyDefinition`, so source maps map the call back to it
+        self.ctx.helper_call_expr(Helper::DefineProperty, prop.span, arguments, ctx)
     }
 
     /// `Object.defineProperty(<assignee>, _prop, {writable: true, value: value})`
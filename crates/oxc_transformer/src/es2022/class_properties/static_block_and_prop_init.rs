@@ -7,13 +7,20 @@ use oxc_ast::{
     ast::*,
     visit::{VisitMut, walk_mut},
 };
-use oxc_syntax::scope::{ScopeFlags, ScopeId};
-use oxc_traverse::TraverseCtx;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::ScopeTree;
+use oxc_span::GetSpan;
+use oxc_syntax::{
+    reference::ReferenceId,
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolId,
+};
+use oxc_traverse::{BoundIdentifier, TraverseCtx};
 
-use crate::utils::ast_builder::wrap_statements_in_arrow_function_iife;
+use crate::utils::ast_builder::{create_iife, wrap_statements_in_arrow_function_iife};
 
 use super::{
-    ClassProperties,
+    ClassProperties, ClassPropertiesOptions, ClassesStack,
     super_converter::{ClassPropertiesSuperConverter, ClassPropertiesSuperConverterMode},
 };
 
@@ -22,14 +29,63 @@ impl<'a> ClassProperties<'a, '_> {
     ///
     /// Replace `this`, and references to class name, with temp var for class. Transform `super`.
     /// See below for full details of transforms.
+    ///
+    /// If code outside class is sloppy mode, and `strict_mode_iife_for_static_initializers` option
+    /// is enabled, wrap initializer in a strict mode IIFE, instead of converting its scopes to sloppy mode.
+    /// `static x = foo();` -> `C.x = (() => { "use strict"; return foo(); })();`
     pub(super) fn transform_static_initializer(
         &mut self,
         value: &mut Expression<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
-        let make_sloppy_mode = !ctx.current_scope_flags().is_strict_mode();
-        let mut replacer = StaticVisitor::new(make_sloppy_mode, true, self, ctx);
+        // Not cached in `ClassProperties`. Getting scope flags is just an index into `ScopeTree`,
+        // and strictness can differ between classes (e.g. a class nested in a sloppy mode class's
+        // static initializer is outside the outer class once it's transformed, so may be sloppy mode).
+        let is_strict = self
+            .current_class()
+            .static_initializer_strictness
+            .unwrap_or_else(|| ctx.current_scope_flags().is_strict_mode());
+        self.transform_static_initializer_with_strictness(value, is_strict, ctx);
+    }
+
+    /// Transform static property initializer, with strictness of code outside class provided
+    /// by caller, instead of obtained from flags of current scope.
+    ///
+    /// Otherwise identical to [`Self::transform_static_initializer`].
+    ///
+    /// Used for classes whose strictness was provided with
+    /// [`ClassProperties::set_static_initializer_strictness`]
+    /// (`Transformer::with_static_initializer_strictness`).
+    pub fn transform_static_initializer_with_strictness(
+        &mut self,
+        value: &mut Expression<'a>,
+        is_strict: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!(
+            "transform_static_initializer",
+            start = value.span().start,
+            end = value.span().end,
+            walk_deep = tracing::field::Empty
+        )
+        .entered();
+
+        let is_sloppy_mode = !is_strict;
+        let wrap_in_strict_mode_iife =
+            is_sloppy_mode && self.strict_mode_iife_for_static_initializers;
+        let make_sloppy_mode = is_sloppy_mode && !wrap_in_strict_mode_iife;
+
+        let scope_id = ctx.current_scope_id();
+        let mut replacer = StaticVisitor::new(make_sloppy_mode, true, scope_id, self, ctx);
         replacer.visit_expression(value);
+        replacer.move_class_name_references();
+
+        if wrap_in_strict_mode_iife {
+            // First-level scopes have been reparented to current scope by `StaticVisitor`.
+            // Wrapping reparents them again, to be children of the IIFE's scope.
+            *value = create_iife(ctx.ast.move_expression(value), false, true, ctx);
+        }
     }
 
     /// Transform static block.
@@ -42,9 +98,10 @@ impl<'a> ClassProperties<'a, '_> {
     /// Replace `this`, and references to class name, with temp var for class. Transform `super`.
     /// See below for full details of transforms.
     ///
-    /// TODO: Add tests for this if there aren't any already.
-    /// Include tests for evaluation order inc that static block goes before class expression
-    /// unless also static properties, or static block uses class name.
+    /// `await` is not valid in a static block, and semantic reports it as a syntax error.
+    /// No diagnostic is raised here. If transform is run on such code regardless, `await` is left as is,
+    /// and `this` in its argument is transformed as usual.
+    /// `static { await this.init(); }` -> `await _C.init();`
     pub(super) fn convert_static_block(
         &mut self,
         block: &mut StaticBlock<'a>,
@@ -59,6 +116,15 @@ impl<'a> ClassProperties<'a, '_> {
         block: &mut StaticBlock<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) -> Expression<'a> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!(
+            "transform_static_block",
+            start = block.span.start,
+            end = block.span.end,
+            walk_deep = tracing::field::Empty
+        )
+        .entered();
+
         let scope_id = block.scope_id();
         let outer_scope_strict_flag = ctx.current_scope_flags() & ScopeFlags::StrictMode;
         let make_sloppy_mode = outer_scope_strict_flag == ScopeFlags::empty();
@@ -80,8 +146,9 @@ impl<'a> ClassProperties<'a, '_> {
 
         // Wrap statements in an IIFE.
         // Note: Do not reparent scopes.
-        let mut replacer = StaticVisitor::new(make_sloppy_mode, false, self, ctx);
+        let mut replacer = StaticVisitor::new(make_sloppy_mode, false, scope_id, self, ctx);
         replacer.visit_statements(stmts);
+        replacer.move_class_name_references();
 
         let scope_flags = outer_scope_strict_flag | ScopeFlags::Function | ScopeFlags::Arrow;
         *ctx.scopes_mut().get_flags_mut(scope_id) = scope_flags;
@@ -100,8 +167,10 @@ impl<'a> ClassProperties<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) -> Expression<'a> {
         // Note: Reparent scopes
-        let mut replacer = StaticVisitor::new(make_sloppy_mode, true, self, ctx);
+        let outer_scope_id = ctx.current_scope_id();
+        let mut replacer = StaticVisitor::new(make_sloppy_mode, true, outer_scope_id, self, ctx);
         replacer.visit_expression(expr);
+        replacer.move_class_name_references();
 
         // Delete scope for static block
         ctx.scopes_mut().delete_scope(scope_id);
@@ -115,27 +184,115 @@ impl<'a> ClassProperties<'a, '_> {
         ident: &mut IdentifierReference<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
-        // Check identifier is reference to class name
-        let class_details = self.current_class_mut();
-        let class_name_symbol_id = class_details.bindings.name_symbol_id();
-        let Some(class_name_symbol_id) = class_name_symbol_id else { return };
+        if let Some(symbol_id) = self.get_class_name_reference_symbol_id(ident, ctx) {
+            let temp_binding = self.current_class_mut().bindings.get_or_init_static_binding(ctx);
+            Self::rename_class_name_reference(ident, symbol_id, temp_binding, false, ctx);
+        }
+    }
 
-        let reference_id = ident.reference_id();
-        let reference = ctx.symbols().get_reference(reference_id);
-        let Some(symbol_id) = reference.symbol_id() else { return };
+    /// Returns `true` if references to class name in static initializers of current class
+    /// should be left as is (`preserve_class_name_references` option).
+    fn preserves_class_name_references(&self) -> bool {
+        self.preserve_class_name_references && self.current_class().is_declaration
+    }
 
-        if symbol_id != class_name_symbol_id {
-            return;
-        }
+    /// Returns `true` if static property initializers / static blocks of `class` will be walked
+    /// in full when they're transformed, rather than only as far as functions and other constructs
+    /// which define a `this`. See [`StaticVisitor::walk_deep`].
+    ///
+    /// If `true` for a class whose static initializers don't all walk deep, returns `true`.
+    /// This can only happen when code outside class is sloppy mode, and
+    /// `strict_mode_iife_for_static_initializers` option is enabled - static blocks are walked deep
+    /// to convert their scopes to sloppy mode, but static property initializers are not.
+    ///
+    /// Does not consider whether class has any static initializers which are moved out of the class.
+    /// If it doesn't, no walk happens at all.
+    ///
+    /// Can be called at any point in traversal, or before transforming, without creating the transform.
+    /// Only uses the options, the class's AST, and the scope tree.
+    /// `static_initializer_strictness` is strictness of code outside class provided by caller, if any.
+    /// See [`Self::set_static_initializer_strictness`].
+    pub fn will_walk_deep(
+        options: &ClassPropertiesOptions,
+        transform_static_blocks: bool,
+        class: &Class<'a>,
+        scopes: &ScopeTree,
+        static_initializer_strictness: Option<bool>,
+    ) -> bool {
+        let is_declaration = class.r#type == ClassType::ClassDeclaration;
+        // Anonymous `export default class {}` with static properties is given a name binding
+        // when entering class body. See `ClassProperties::transform_class_body_on_entry`.
+        let has_name = class.id.is_some()
+            || (is_declaration
+                && class.body.body.iter().any(|element| {
+                    matches!(element, ClassElement::PropertyDefinition(prop) if prop.r#static)
+                }));
+        let preserves_class_name_references =
+            options.preserve_class_name_references && is_declaration;
+
+        let outer_scope_id = scopes.get_parent_id(class.scope_id());
+        let is_sloppy_mode =
+            outer_scope_id.is_some_and(|scope_id| !scopes.get_flags(scope_id).is_strict_mode());
+        // Strictness provided by caller only applies to static property initializers
+        let is_static_prop_sloppy_mode =
+            static_initializer_strictness.map_or(is_sloppy_mode, |is_strict| !is_strict);
+        // `strict_mode_iife_for_static_initializers` only applies to static property initializers
+        let has_static_block = transform_static_blocks
+            && class
+                .body
+                .body
+                .iter()
+                .any(|element| matches!(element, ClassElement::StaticBlock(_)));
+        let make_sloppy_mode = (is_static_prop_sloppy_mode
+            && !options.strict_mode_iife_for_static_initializers)
+            || (is_sloppy_mode && has_static_block);
+
+        StaticVisitor::walk_deep(make_sloppy_mode, has_name, preserves_class_name_references)
+    }
+
+    /// If identifier is a reference to class name, return `SymbolId` of class name binding.
+    fn get_class_name_reference_symbol_id(
+        &self,
+        ident: &IdentifierReference<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<SymbolId> {
+        let class_name_symbol_id = self.current_class().bindings.name_symbol_id()?;
+
+        // An unresolved reference cannot be a reference to class name, because class name binding
+        // is always in scope within the class. Semantic resolves references even in scopes containing
+        // a direct `eval`, so that doesn't change this. Code inside `eval("C")` is not transformed
+        // (and can't be), so it references class binding `C` at runtime, not the temp var.
+        //
+        // A binding which shadows class name is a different symbol, so is not replaced.
+        // `class C { static x = () => { let C = 1; return C; }; }`
+        let symbol_id = ctx.symbols().get_reference(ident.reference_id()).symbol_id()?;
+        (symbol_id == class_name_symbol_id).then_some(symbol_id)
+    }
 
-        // Identifier is reference to class name. Rename it.
-        let temp_binding = class_details.bindings.get_or_init_static_binding(ctx);
+    /// Rename reference to class name to refer to temp var for class.
+    ///
+    /// If `defer_resolved_reference_update` is `true`, only the identifier is renamed.
+    /// Caller is then responsible for rebinding its `Reference` to temp var, which [`StaticVisitor`]
+    /// does in bulk after traversal with [`TraverseCtx::rebind_references`].
+    ///
+    /// Returns `true` if reference was renamed.
+    fn rename_class_name_reference(
+        ident: &mut IdentifierReference<'a>,
+        symbol_id: SymbolId,
+        temp_binding: &BoundIdentifier<'a>,
+        defer_resolved_reference_update: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> bool {
+        // No need to do anything if class name is being used in place of temp var.
+        if temp_binding.symbol_id == symbol_id {
+            return false;
+        }
         ident.name = temp_binding.name;
 
-        let symbols = ctx.symbols_mut();
-        symbols.get_reference_mut(reference_id).set_symbol_id(temp_binding.symbol_id);
-        symbols.delete_resolved_reference(symbol_id, reference_id);
-        symbols.add_resolved_reference(temp_binding.symbol_id, reference_id);
+        if !defer_resolved_reference_update {
+            ctx.rebind_reference(ident.reference_id(), symbol_id, temp_binding.symbol_id);
+        }
+        true
     }
 }
 
@@ -148,6 +305,7 @@ impl<'a> ClassProperties<'a, '_> {
 ///    * Class expression:
 ///      * `x = class C { static x = this.y; }` -> `var _C; x = (_C = class C {}, _C.x = _C.y, _C)`
 ///      * `C = class C { static { this.x(); } }` -> `var _C; C = (_C = class C {}, _C.x(), _C)`
+///    * If a `StaticThisReplacement` callback is provided, `this` is replaced with expression it returns instead.
 /// 2. Reference to class name to class temp var.
 ///    * Class declaration:
 ///      * `class C { static x = C.y; }` -> `var _C; class C {}; _C = C; C.x = _C.y;`
@@ -155,9 +313,15 @@ impl<'a> ClassProperties<'a, '_> {
 ///    * Class expression:
 ///      * `x = class C { static x = C.y; }` -> `var _C; x = (_C = class C {}, _C.x = _C.y, _C)`
 ///      * `x = class C { static { C.x(); } }` -> `var _C; x = (_C = class C {}, _C.x(), _C)`
+///    * If `preserve_class_name_references` option is set, references in class declarations are left as is.
 /// 3. `super` to transpiled super.
 ///    * e.g. `super.prop` -> `_superPropGet(_Class, "prop", this)` (in static private method)
 ///      or `_superPropGet(_Class, "prop", _Class)` (in static property initializer or static block)
+///    * `delete super.prop` -> IIFE which throws a `ReferenceError`
+/// 4. `new.target` to `void 0`.
+///    * `new.target` is always `undefined` in a static initializer/block. Outside the class, it'd refer
+///      to `new.target` of an enclosing function, or be a syntax error at top level.
+///    * `import.meta` has same meaning inside and outside the class, so is left as is.
 ///
 /// Also:
 /// * Update parent `ScopeId` of first level of scopes, if `reparent_scopes == true`.
@@ -185,21 +349,35 @@ impl<'a> ClassProperties<'a, '_> {
 /// If this class has no name, and no `ScopeFlags` need updating, then we only need to transform `this`,
 /// and re-parent first-level scopes. So can skip traversing into functions and other contexts which have
 /// their own `this`.
+///
+/// There is no hook for custom rewrites during this walk. The transformer has no plugin API -
+/// it's configured only via `TransformOptions`. To apply custom rewrites to static initializers,
+/// run a separate `Traverse` pass over the output, where they're ordinary expressions after the class.
 //
-// TODO(improve-on-babel): Unnecessary to create temp var for class declarations if either:
-// 1. Class name binding is not mutated.
-// 2. `this` / reference to class name / private field is not in a nested function, so we know the
-//    code runs immediately, before any mutation of the class name binding can occur.
+// If `skip_temp_var_for_unmutated_class` option is enabled, and class name binding of a class
+// declaration is never mutated, no temp var is created. Class name is used in its place.
+//
+// TODO(improve-on-babel): Also unnecessary to create temp var for class declarations if
+// `this` / reference to class name / private field is not in a nested function, so we know the
+// code runs immediately, before any mutation of the class name binding can occur.
 //
 // TODO(improve-on-babel): Updating `ScopeFlags` for strict mode makes semantic correctly for the output,
 // but actually the transform isn't right. Should wrap initializer/block in a strict mode IIFE so that
 // code runs in strict mode, as it was before within class body.
+// `strict_mode_iife_for_static_initializers` option does this for static property initializers,
+// but not yet for static blocks.
+//
+// Note: No special handling is required for `with` statements. Class bodies are always strict mode,
+// so `with` in a static initializer / static block is a syntax error. If semantic errors are ignored
+// and transform runs anyway, `WithStatement` has no scope of its own, so it doesn't affect scope flags
+// - any scopes within it are visited and flipped to sloppy mode like all others.
 struct StaticVisitor<'a, 'ctx, 'v> {
     /// `true` if class has name, or `ScopeFlags` need updating.
     /// Either of these neccesitates walking the whole tree. If neither applies, we only need to walk
     /// as far as functions and other constructs which define a `this`.
     walk_deep: bool,
-    /// `true` if should make scopes sloppy mode
+    /// `true` if should make scopes sloppy mode.
+    /// Always `false` in ES modules, as code outside class is strict mode too.
     make_sloppy_mode: bool,
     /// Incremented when entering a different `this` context, decremented when exiting it.
     /// `this` should be transformed when `this_depth == 0`.
@@ -215,6 +393,26 @@ struct StaticVisitor<'a, 'ctx, 'v> {
     /// the `ScopeId` of the old static block, so we don't need to reparent scopes anyway,
     /// so `scope_depth` is ignored.
     scope_depth: u32,
+    /// Incremented when entering an expression, decremented when exiting it.
+    /// If exceeds `max_static_initializer_depth` option, stop walking to avoid stack overflow.
+    expression_depth: u32,
+    /// `true` if `expression_depth` has exceeded limit. Used to only raise error once.
+    exceeded_max_depth: bool,
+    /// Incremented when entering a scope which has no `ScopeId` (AST which has not had semantic
+    /// run on it), and any scope within it. Decremented when exiting those scopes.
+    /// While non-zero, `current_scope_id` is not updated.
+    missing_scope_id_depth: u32,
+    /// `true` if a scope with no `ScopeId` has been found. Used to only raise warning once.
+    found_missing_scope_id: bool,
+    /// `ScopeId` of current scope.
+    /// Used as parent scope for any new scopes created while transforming `delete super.prop`.
+    current_scope_id: ScopeId,
+    /// IDs of references to class name which have been renamed to temp var.
+    class_name_reference_ids: Vec<ReferenceId>,
+    /// Binding for class temp var (or class name, if it's used in place of temp var).
+    /// Cached on first use, so replacing many `this` / references to class name only requires
+    /// getting binding from `ClassBindings` once.
+    class_binding: Option<BoundIdentifier<'a>>,
     /// Converter for `super` expressions.
     super_converter: ClassPropertiesSuperConverter<'a, 'ctx, 'v>,
     /// `TraverseCtx` object.
@@ -225,11 +423,22 @@ impl<'a, 'ctx, 'v> StaticVisitor<'a, 'ctx, 'v> {
     fn new(
         make_sloppy_mode: bool,
         reparent_scopes: bool,
+        scope_id: ScopeId,
         class_properties: &'v mut ClassProperties<'a, 'ctx>,
         ctx: &'v mut TraverseCtx<'a>,
     ) -> Self {
-        let walk_deep =
-            make_sloppy_mode || class_properties.current_class().bindings.name.is_some();
+        let walk_deep = Self::walk_deep(
+            make_sloppy_mode,
+            class_properties.current_class().bindings.name.is_some(),
+            class_properties.preserves_class_name_references(),
+        );
+
+        #[cfg(feature = "transform_stats")]
+        super::ClassPropertiesStats::record(walk_deep);
+
+        // Record on span created in `transform_static_initializer` or `convert_static_block_to_expression`
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("walk_deep", walk_deep);
 
         // Set `scope_depth` to 1 initially if don't need to reparent scopes
         // (static block where converting to IIFE)
@@ -241,6 +450,13 @@ impl<'a, 'ctx, 'v> StaticVisitor<'a, 'ctx, 'v> {
             make_sloppy_mode,
             this_depth: 0,
             scope_depth,
+            expression_depth: 0,
+            exceeded_max_depth: false,
+            missing_scope_id_depth: 0,
+            found_missing_scope_id: false,
+            current_scope_id: scope_id,
+            class_name_reference_ids: Vec::new(),
+            class_binding: None,
             super_converter: ClassPropertiesSuperConverter::new(
                 ClassPropertiesSuperConverterMode::Static,
                 class_properties,
@@ -248,75 +464,114 @@ impl<'a, 'ctx, 'v> StaticVisitor<'a, 'ctx, 'v> {
             ctx,
         }
     }
+
+    /// Returns `true` if static initializer / static block needs walking in full.
+    ///
+    /// Shared by [`StaticVisitor::new`] and [`ClassProperties::will_walk_deep`],
+    /// so that prediction always matches actual traversal.
+    fn walk_deep(
+        make_sloppy_mode: bool,
+        has_name: bool,
+        preserves_class_name_references: bool,
+    ) -> bool {
+        make_sloppy_mode || (has_name && !preserves_class_name_references)
+    }
 }
 
 impl<'a> VisitMut<'a> for StaticVisitor<'a, '_, '_> {
     #[inline]
     fn visit_expression(&mut self, expr: &mut Expression<'a>) {
-        match expr {
-            // `this`
-            Expression::ThisExpression(this_expr) => {
-                let span = this_expr.span;
-                self.replace_this_with_temp_var(expr, span);
-                return;
-            }
-            // `delete this`
-            Expression::UnaryExpression(unary_expr) => {
-                if unary_expr.operator == UnaryOperator::Delete
-                    && matches!(&unary_expr.argument, Expression::ThisExpression(_))
-                {
-                    let span = unary_expr.span;
-                    self.replace_delete_this_with_true(expr, span);
-                    return;
-                }
-            }
-            // `super.prop`
-            Expression::StaticMemberExpression(_) if self.this_depth == 0 => {
-                self.super_converter.transform_static_member_expression(expr, self.ctx);
-            }
-            // `super[prop]`
-            Expression::ComputedMemberExpression(_) if self.this_depth == 0 => {
-                self.super_converter.transform_computed_member_expression(expr, self.ctx);
-            }
-            // `super.prop()`
-            Expression::CallExpression(call_expr) if self.this_depth == 0 => {
-                self.super_converter
-                    .transform_call_expression_for_super_member_expr(call_expr, self.ctx);
-            }
-            // `super.prop = value`, `super.prop += value`, `super.prop ??= value`
-            Expression::AssignmentExpression(_) if self.this_depth == 0 => {
-                self.super_converter
-                    .transform_assignment_expression_for_super_assignment_target(expr, self.ctx);
-            }
-            // `super.prop++`, `--super.prop`
-            Expression::UpdateExpression(_) if self.this_depth == 0 => {
-                self.super_converter
-                    .transform_update_expression_for_super_assignment_target(expr, self.ctx);
-            }
-            _ => {}
+        if self.expression_depth
+            >= self.super_converter.class_properties.max_static_initializer_depth
+        {
+            self.report_max_depth_exceeded(expr.span());
+            return;
         }
 
-        walk_mut::walk_expression(self, expr);
+        let depths = self.depths();
+
+        self.expression_depth += 1;
+        self.transform_expression(expr);
+        self.expression_depth -= 1;
+
+        self.debug_assert_depths_balanced(depths);
     }
 
     /// Transform reference to class name to temp var
     fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
-        self.super_converter.class_properties.replace_class_name_with_temp_var(ident, self.ctx);
+        let class_properties = &self.super_converter.class_properties;
+
+        // `arguments` in a static initializer / static block is a syntax error (semantic reports it).
+        // If transform runs regardless, moving the code outside the class would make `arguments`
+        // silently bind to `arguments` of an enclosing function, so raise an error instead.
+        // `arguments` in a nested function refers to that function's `arguments`, so is fine.
+        if self.this_depth == 0 && ident.name == "arguments" {
+            class_properties.ctx.error(
+                OxcDiagnostic::error(
+                    "'arguments' is not allowed in class field initializer or static initialization block",
+                )
+                .with_label(ident.span),
+            );
+            return;
+        }
+
+        if class_properties.preserves_class_name_references() {
+            return;
+        }
+
+        if let Some(symbol_id) =
+            class_properties.get_class_name_reference_symbol_id(ident, self.ctx)
+        {
+            let temp_binding = self.get_class_binding();
+            // References are rebound after traversal. See `move_class_name_references`.
+            if ClassProperties::rename_class_name_reference(
+                ident,
+                symbol_id,
+                &temp_binding,
+                true,
+                self.ctx,
+            ) {
+                self.class_name_reference_ids.push(ident.reference_id());
+            }
+        }
     }
 
     /// Convert scope to sloppy mode if `self.make_sloppy_mode == true`.
     // `#[inline]` because called from many `walk` functions and is small.
     #[inline]
     fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        let Some(scope_id) = scope_id.get() else {
+            self.enter_scope_without_id();
+            return;
+        };
+        if self.missing_scope_id_depth == 0 {
+            self.current_scope_id = scope_id;
+        } else {
+            // Within a scope without a `ScopeId`. Don't track current scope, because can't get back
+            // to the right scope when exiting the scope without a `ScopeId`.
+            self.missing_scope_id_depth += 1;
+        }
         if self.make_sloppy_mode {
-            let scope_id = scope_id.get().unwrap();
             *self.ctx.scopes_mut().get_flags_mut(scope_id) -= ScopeFlags::StrictMode;
         }
     }
 
+    /// Set `current_scope_id` back to parent scope.
+    /// First-level scopes have already been reparented, so parent is the correct scope.
+    #[inline]
+    fn leave_scope(&mut self) {
+        if self.missing_scope_id_depth > 0 {
+            self.missing_scope_id_depth -= 1;
+            return;
+        }
+        self.current_scope_id = self.ctx.scopes().get_parent_id(self.current_scope_id).unwrap();
+    }
+
     // Increment `this_depth` when entering code where `this` refers to a different `this`
     // from `this` within this class, and decrement it when exiting.
     // Therefore `this_depth == 0` when `this` refers to the `this` which needs to be transformed.
+    // This includes generator and async functions, so `this` in `yield this` / `await this`
+    // in a nested function is not transformed.
     //
     // Or, if class has no name, and `ScopeFlags` don't need updating, stop traversing entirely.
     // No scopes need flags updating, so no point searching for them.
@@ -325,9 +580,12 @@ impl<'a> VisitMut<'a> for StaticVisitor<'a, '_, '_> {
 
     #[inline]
     fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        let depths = self.depths();
+
         let parent_sloppy_mode = self.make_sloppy_mode;
         if self.make_sloppy_mode && func.has_use_strict_directive() {
-            // Function has a `"use strict"` directive in body
+            // Function has a `"use strict"` directive in body.
+            // Being async or a generator does not affect strictness, only the directive does.
             self.make_sloppy_mode = false;
         }
 
@@ -342,10 +600,14 @@ impl<'a> VisitMut<'a> for StaticVisitor<'a, '_, '_> {
         }
 
         self.make_sloppy_mode = parent_sloppy_mode;
+
+        self.debug_assert_depths_balanced(depths);
     }
 
     #[inline]
     fn visit_arrow_function_expression(&mut self, func: &mut ArrowFunctionExpression<'a>) {
+        let depths = self.depths();
+
         let parent_sloppy_mode = self.make_sloppy_mode;
         if self.make_sloppy_mode && func.has_use_strict_directive() {
             // Arrow function has a `"use strict"` directive in body
@@ -359,16 +621,27 @@ impl<'a> VisitMut<'a> for StaticVisitor<'a, '_, '_> {
         self.scope_depth -= 1;
 
         self.make_sloppy_mode = parent_sloppy_mode;
+
+        self.debug_assert_depths_balanced(depths);
     }
 
     #[inline]
     fn visit_class(&mut self, class: &mut Class<'a>) {
+        let depths = self.depths();
+
         let parent_sloppy_mode = self.make_sloppy_mode;
         // Classes are always strict mode
         self.make_sloppy_mode = false;
 
         self.reparent_scope_if_first_level(&class.scope_id);
 
+        // Don't increment `this_depth` for class itself. `super_class` is evaluated in current
+        // `this` context, so `this` in it is transformed.
+        // `class C { static x = class extends this.Base {}; }` -> `C.x = class extends _C.Base {}`
+        // Property values, methods and static blocks of nested class increment `this_depth`.
+        // Note: `super_class` of the class being transformed is never visited by `StaticVisitor`,
+        // as it's not part of a static initializer. `this` there is left as is.
+
         // TODO: Need to visit decorators *before* incrementing `scope_depth`.
         // Decorators could contain scopes. e.g. `@(() => {}) class C {}`
         self.scope_depth += 1;
@@ -376,10 +649,14 @@ impl<'a> VisitMut<'a> for StaticVisitor<'a, '_, '_> {
         self.scope_depth -= 1;
 
         self.make_sloppy_mode = parent_sloppy_mode;
+
+        self.debug_assert_depths_balanced(depths);
     }
 
     #[inline]
     fn visit_static_block(&mut self, block: &mut StaticBlock<'a>) {
+        let depths = self.depths();
+
         // Not possible that `self.scope_depth == 0` here, because a `StaticBlock`
         // can only be in a class, and that class would be the first-level scope.
         // So no need to call `reparent_scope_if_first_level`.
@@ -389,10 +666,14 @@ impl<'a> VisitMut<'a> for StaticVisitor<'a, '_, '_> {
         self.this_depth += 1;
         walk_mut::walk_static_block(self, block);
         self.this_depth -= 1;
+
+        self.debug_assert_depths_balanced(depths);
     }
 
     #[inline]
     fn visit_ts_module_block(&mut self, block: &mut TSModuleBlock<'a>) {
+        let depths = self.depths();
+
         // Not possible that `self.scope_depth == 0` here, because a `TSModuleBlock`
         // can only be in a function, and that function would be the first-level scope.
         // So no need to call `reparent_scope_if_first_level`.
@@ -410,10 +691,14 @@ impl<'a> VisitMut<'a> for StaticVisitor<'a, '_, '_> {
         }
 
         self.make_sloppy_mode = parent_sloppy_mode;
+
+        self.debug_assert_depths_balanced(depths);
     }
 
     #[inline]
     fn visit_property_definition(&mut self, prop: &mut PropertyDefinition<'a>) {
+        let depths = self.depths();
+
         // `this` in computed key of property or method refers to `this` of parent class.
         // So visit computed `key` within current `this` scope,
         // but increment `this_depth` before visiting `value`.
@@ -428,29 +713,53 @@ impl<'a> VisitMut<'a> for StaticVisitor<'a, '_, '_> {
         // can only be in a class, and that class would be the first-level scope.
         // So no need to call `reparent_scope_if_first_level`.
 
-        // TODO: Are decorators in scope?
+        // Decorators are evaluated in same `this` context as computed keys, so `this` in a decorator
+        // refers to `this` of parent class. Visit them before incrementing `this_depth`.
+        // ```js
+        // class Outer {
+        //   static prop = class Inner { @dec(this) accessor x = 1; };
+        // }
+        // ```
+        // References to class name in decorators are replaced with temp var, same as anywhere else.
+        // `_Outer` is the same object as `Outer`, so e.g. `Reflect.metadata` lookups keyed on the class
+        // are unaffected. Class name can't be retained, because for a class expression, it isn't bound
+        // outside the class. Use `skip_temp_var_for_unmutated_class` option to retain class name
+        // where it's safe to do so.
         self.visit_decorators(&mut prop.decorators);
+        // Ditto class name in computed keys. Nested class's keys are evaluated while the outer class's
+        // static initializers run, at which point class name binding can't have been reassigned,
+        // so temp var holds the same value. Top-level class's own keys are not visited here -
+        // they're evaluated outside the class, and retain class name.
         if prop.computed {
             self.visit_property_key(&mut prop.key);
         }
 
         // `walk_deep` must be `true` or we couldn't get here, because a `PropertyDefinition`
-        // must be in a class, and traversal would have stopped in `visit_class` if it wasn't
+        // must be in a class, and traversal would have stopped in `visit_class` if it wasn't.
+        // `declare` fields have no value (and are removed by TypeScript transform before we get here
+        // anyway), so there's nothing to visit.
         if let Some(value) = &mut prop.value {
             self.this_depth += 1;
             self.visit_expression(value);
             self.this_depth -= 1;
         }
+
+        self.debug_assert_depths_balanced(depths);
     }
 
     #[inline]
     fn visit_accessor_property(&mut self, prop: &mut AccessorProperty<'a>) {
+        let depths = self.depths();
+
         // Not possible that `self.scope_depth == 0` here, because an `AccessorProperty`
         // can only be in a class, and that class would be the first-level scope.
         // So no need to call `reparent_scope_if_first_level`.
 
-        // Treat `key` and `value` in same way as `visit_property_definition` above.
-        // TODO: Are decorators in scope?
+        // Treat `decorators`, `key` and `value` in same way as `visit_property_definition` above.
+        // `super` in `value` refers to the nested class's super class, same as `this` does,
+        // so incrementing `this_depth` also prevents it being transformed.
+        // Static getters / setters are not moved out of the class, so `super` in them is left as is.
+        // Static private accessors are transformed by `PrivateMethodVisitor` instead.
         self.visit_decorators(&mut prop.decorators);
         if prop.computed {
             self.visit_property_key(&mut prop.key);
@@ -463,6 +772,8 @@ impl<'a> VisitMut<'a> for StaticVisitor<'a, '_, '_> {
             self.visit_expression(value);
             self.this_depth -= 1;
         }
+
+        self.debug_assert_depths_balanced(depths);
     }
 
     // Remaining visitors are the only other types which have a scope which can be first-level
@@ -473,9 +784,31 @@ impl<'a> VisitMut<'a> for StaticVisitor<'a, '_, '_> {
     //
     // In a static block which contains statements, we're wrapping it in an IIFE which takes on
     // the `ScopeId` of the old static block, so we don't need to reparent scopes anyway.
+    //
+    // `TSTypeQuery` (`typeof C` in a type) has no scope, so needs no special handling.
+    // In practice, TypeScript transform has already removed all types by the time initializers
+    // are transformed. `static x = y as typeof C` -> `C.x = y`.
+
+    #[inline]
+    fn visit_ts_type(&mut self, ty: &mut TSType<'a>) {
+        // Types can't contain anything which needs transforming, only scopes which need reparenting.
+        // If types will have been erased, skip them.
+        if self.super_converter.class_properties.skip_ts_types_in_static_initializers {
+            return;
+        }
+        walk_mut::walk_ts_type(self, ty);
+    }
+
+    #[inline]
+    fn visit_ts_type_name(&mut self, _name: &mut TSTypeName<'a>) {
+        // Type names e.g. `C` in `x as C` refer to the class's type, not its value,
+        // so must not be replaced with temp var. They contain no scopes, so nothing else to do.
+    }
 
     #[inline]
     fn visit_ts_conditional_type(&mut self, conditional: &mut TSConditionalType<'a>) {
+        let depths = self.depths();
+
         self.reparent_scope_if_first_level(&conditional.scope_id);
 
         // `check_type` field is outside `TSConditionalType`'s scope
@@ -488,17 +821,25 @@ impl<'a> VisitMut<'a> for StaticVisitor<'a, '_, '_> {
         self.visit_ts_type(&mut conditional.true_type);
         self.scope_depth -= 1;
 
+        self.leave_scope();
+
         // `false_type` field is outside `TSConditionalType`'s scope
         self.visit_ts_type(&mut conditional.false_type);
+
+        self.debug_assert_depths_balanced(depths);
     }
 
     #[inline]
     fn visit_ts_method_signature(&mut self, signature: &mut TSMethodSignature<'a>) {
+        let depths = self.depths();
+
         self.reparent_scope_if_first_level(&signature.scope_id);
 
         self.scope_depth += 1;
         walk_mut::walk_ts_method_signature(self, signature);
         self.scope_depth -= 1;
+
+        self.debug_assert_depths_balanced(depths);
     }
 
     #[inline]
@@ -506,46 +847,584 @@ impl<'a> VisitMut<'a> for StaticVisitor<'a, '_, '_> {
         &mut self,
         signature: &mut TSConstructSignatureDeclaration<'a>,
     ) {
+        let depths = self.depths();
+
         self.reparent_scope_if_first_level(&signature.scope_id);
 
         self.scope_depth += 1;
         walk_mut::walk_ts_construct_signature_declaration(self, signature);
         self.scope_depth -= 1;
+
+        self.debug_assert_depths_balanced(depths);
     }
 
     #[inline]
     fn visit_ts_mapped_type(&mut self, mapped: &mut TSMappedType<'a>) {
+        let depths = self.depths();
+
         self.reparent_scope_if_first_level(&mapped.scope_id);
 
         self.scope_depth += 1;
         walk_mut::walk_ts_mapped_type(self, mapped);
         self.scope_depth -= 1;
+
+        self.debug_assert_depths_balanced(depths);
     }
 }
 
 impl<'a> StaticVisitor<'a, '_, '_> {
+    /// Get current `this_depth` and `scope_depth`.
+    ///
+    /// Used with [`Self::debug_assert_depths_balanced`] to check each visitor which alters
+    /// the counters restores them before it exits. An unbalanced increment / decrement
+    /// would result in `this` or scopes being transformed incorrectly, without any other error.
+    #[inline]
+    fn depths(&self) -> (u32, u32) {
+        (self.this_depth, self.scope_depth)
+    }
+
+    /// Check `this_depth` and `scope_depth` are same as when visitor was entered.
+    /// No-op in release builds.
+    #[inline]
+    fn debug_assert_depths_balanced(&self, depths: (u32, u32)) {
+        debug_assert_eq!(
+            self.depths(),
+            depths,
+            "`StaticVisitor` depth counters (`this_depth`, `scope_depth`) are unbalanced"
+        );
+    }
+
+    /// Transform `this`, `new.target` and `super` in an expression, and walk its children.
+    fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            // `this`
+            Expression::ThisExpression(this_expr) => {
+                let span = this_expr.span;
+                self.replace_this_with_temp_var(expr, span);
+                return;
+            }
+            // `new.target`
+            Expression::MetaProperty(meta) if meta.meta.name == "new" => {
+                let span = meta.span;
+                self.replace_new_target_with_void_0(expr, span);
+                return;
+            }
+            // `delete this`, `delete super.prop`, `delete super[prop]`
+            Expression::UnaryExpression(unary_expr)
+                if unary_expr.operator == UnaryOperator::Delete =>
+            {
+                match &mut unary_expr.argument {
+                    Expression::ThisExpression(_) => {
+                        if self.super_converter.class_properties.preserve_delete_this {
+                            self.replace_this_in_delete_this(&mut unary_expr.argument);
+                        } else {
+                            let span = unary_expr.span;
+                            self.replace_delete_this_with_true(expr, span);
+                        }
+                        return;
+                    }
+                    Expression::StaticMemberExpression(member)
+                        if self.this_depth == 0 && member.object.is_super() =>
+                    {
+                        self.super_converter.transform_unary_expression_for_super_member_expr(
+                            expr,
+                            self.current_scope_id,
+                            self.ctx,
+                        );
+                        return;
+                    }
+                    Expression::ComputedMemberExpression(member)
+                        if self.this_depth == 0 && member.object.is_super() =>
+                    {
+                        self.visit_expression(&mut member.expression);
+                        self.super_converter.transform_unary_expression_for_super_member_expr(
+                            expr,
+                            self.current_scope_id,
+                            self.ctx,
+                        );
+                        return;
+                    }
+                    _ => {}
+                }
+            }
+            // `super.prop`
+            Expression::StaticMemberExpression(_) if self.this_depth == 0 => {
+                self.super_converter.transform_static_member_expression(expr, self.ctx);
+            }
+            // `super[prop]`
+            Expression::ComputedMemberExpression(_) if self.this_depth == 0 => {
+                self.super_converter.transform_computed_member_expression(expr, self.ctx);
+            }
+            // `super.prop()`
+            Expression::CallExpression(call_expr) if self.this_depth == 0 => {
+                self.super_converter
+                    .transform_call_expression_for_super_member_expr(call_expr, self.ctx);
+            }
+            // `super.prop?.()`
+            Expression::ChainExpression(chain_expr) if self.this_depth == 0 => {
+                self.super_converter
+                    .transform_chain_expression_for_super_member_call(chain_expr, self.ctx);
+            }
+            // `super.prop = value`, `super.prop += value`, `super.prop ??= value`
+            Expression::AssignmentExpression(_) if self.this_depth == 0 => {
+                self.super_converter
+                    .transform_assignment_expression_for_super_assignment_target(expr, self.ctx);
+            }
+            // `super.prop++`, `--super.prop`
+            Expression::UpdateExpression(_) if self.this_depth == 0 => {
+                self.super_converter
+                    .transform_update_expression_for_super_assignment_target(expr, self.ctx);
+            }
+            _ => {}
+        }
+
+        walk_mut::walk_expression(self, expr);
+    }
+
+    /// Raise error for exceeding max expression depth, if not already raised.
+    fn report_max_depth_exceeded(&mut self, span: Span) {
+        if !self.exceeded_max_depth {
+            self.exceeded_max_depth = true;
+            self.super_converter.class_properties.ctx.error(
+                OxcDiagnostic::error(
+                    "Static property initializer or static block is too deeply nested to transform",
+                )
+                .with_label(span),
+            );
+        }
+    }
+
     /// Replace `this` with reference to temp var for class.
     fn replace_this_with_temp_var(&mut self, expr: &mut Expression<'a>, span: Span) {
         if self.this_depth == 0 {
-            let class_details = self.super_converter.class_properties.current_class_mut();
-            let temp_binding = class_details.bindings.get_or_init_static_binding(self.ctx);
+            let class_properties = &self.super_converter.class_properties;
+            if let Some(replacement) = &class_properties.static_this_replacement {
+                Self::assert_in_class(&class_properties.classes_stack);
+                let class_scope_id = class_properties.current_class().scope_id;
+                if let Some(replacement) =
+                    replacement(class_scope_id, self.current_scope_id, span, self.ctx)
+                {
+                    *expr = replacement;
+                    return;
+                }
+            }
+
+            let temp_binding = if self.super_converter.class_properties.separate_temp_var_for_this {
+                self.get_this_binding()
+            } else {
+                self.get_class_binding()
+            };
+            // Retain span of `this`, so source maps map the temp var back to original `this`
             *expr = temp_binding.create_spanned_read_expression(span, self.ctx);
         }
     }
 
+    /// Rebind references to class name which have been renamed to temp var from class name symbol
+    /// to temp var symbol. Must be called after traversal.
+    ///
+    /// Done in bulk, rather than as each reference is renamed, because removing a reference from
+    /// a symbol's resolved references is `O(n)`, so doing it for each reference would be `O(n^2)`.
+    ///
+    /// Renamed references keep their `ReferenceId`s, so there's no separate record of them.
+    /// A later pass can find them in temp var's resolved references, alongside those created for `this`.
+    fn move_class_name_references(&mut self) {
+        if self.class_name_reference_ids.is_empty() {
+            return;
+        }
+        // Both unwraps are safe, because a reference can only be renamed if class has a name,
+        // and `get_class_binding` was called
+        let class_name_symbol_id = self
+            .super_converter
+            .class_properties
+            .current_class()
+            .bindings
+            .name_symbol_id()
+            .unwrap();
+        let temp_symbol_id = self.class_binding.as_ref().unwrap().symbol_id;
+        self.ctx.rebind_references(
+            &self.class_name_reference_ids,
+            class_name_symbol_id,
+            temp_symbol_id,
+        );
+    }
+
+    /// Get binding for class temp var (or class name, if it's used in place of temp var).
+    ///
+    /// Temp var is created on first call, if it doesn't already exist.
+    /// Binding is cached, so subsequent calls don't need to consult `ClassBindings` again.
+    /// Which binding is used can't change during traversal of a single initializer / static block,
+    /// so the cached binding remains valid throughout.
+    fn get_class_binding(&mut self) -> BoundIdentifier<'a> {
+        self.class_binding
+            .get_or_insert_with(|| {
+                Self::assert_in_class(&self.super_converter.class_properties.classes_stack);
+                let class_details = self.super_converter.class_properties.current_class_mut();
+                class_details.bindings.get_or_init_static_binding(self.ctx).clone()
+            })
+            .clone()
+    }
+
+    /// Get binding for temp var which replaces `this`, when `separate_temp_var_for_this` is enabled.
+    ///
+    /// For class expressions, `this` temp var is assigned from class temp var
+    /// (`_Class = class {}, _thisClass = _Class, ...`), so class temp var is created too.
+    fn get_this_binding(&mut self) -> BoundIdentifier<'a> {
+        Self::assert_in_class(&self.super_converter.class_properties.classes_stack);
+        let class_details = self.super_converter.class_properties.current_class_mut();
+        if !class_details.is_declaration {
+            class_details.bindings.get_or_init_static_binding(self.ctx);
+        }
+        class_details.bindings.get_or_init_this_binding(self.ctx).clone()
+    }
+
+    /// Assert that a class is being transformed.
+    ///
+    /// Initializers which don't contain `this` or class name can be transformed without a class
+    /// on `classes_stack`, but ones which do need the class's bindings.
+    /// Without this, `ClassBindings::get_or_init_static_binding` panics with an unhelpful message.
+    #[inline]
+    fn assert_in_class(classes_stack: &ClassesStack<'a>) {
+        debug_assert!(
+            !classes_stack.is_empty(),
+            "Cannot transform `this` or class name in static initializer when no class is being transformed. \
+            `ClassDetails` for the class must be pushed to `classes_stack` first."
+        );
+    }
+
+    /// Replace `new.target` with `void 0`.
+    fn replace_new_target_with_void_0(&self, expr: &mut Expression<'a>, span: Span) {
+        if self.this_depth == 0 {
+            *expr = self.ctx.ast.void_0(span);
+        }
+    }
+
     /// Replace `delete this` with `true`.
+    ///
+    /// `true` takes the span of `delete this`, so comments attached to `delete this` remain attached.
     fn replace_delete_this_with_true(&self, expr: &mut Expression<'a>, span: Span) {
         if self.this_depth == 0 {
             *expr = self.ctx.ast.expression_boolean_literal(span, true);
         }
     }
 
+    /// Replace `this` in `delete this` with temp var, keeping the `delete`.
+    ///
+    /// `delete _C` is a syntax error in strict mode, so temp var is wrapped in a sequence:
+    /// `delete this` -> `delete (0, _C)`. Like `delete this`, that always evaluates to `true`.
+    fn replace_this_in_delete_this(&mut self, argument: &mut Expression<'a>) {
+        if self.this_depth == 0 {
+            let span = argument.span();
+            self.replace_this_with_temp_var(argument, span);
+            let temp_var = self.ctx.ast.move_expression(argument);
+            *argument = self.ctx.ast.expression_sequence(
+                span,
+                self.ctx.ast.vec_from_array([self.ctx.ast.number_0(), temp_var]),
+            );
+        }
+    }
+
     /// Update parent of scope to scope above class if this is a first-level scope.
+    ///
+    /// With `tracing` feature, each reparenting is logged as a `TRACE` event.
     fn reparent_scope_if_first_level(&mut self, scope_id: &Cell<Option<ScopeId>>) {
         if self.scope_depth == 0 {
-            let scope_id = scope_id.get().unwrap();
-            let current_scope_id = self.ctx.current_scope_id();
-            self.ctx.scopes_mut().change_parent_id(scope_id, Some(current_scope_id));
+            // If scope has no `ScopeId`, there's nothing to reparent.
+            // Warning is raised in `enter_scope`.
+            if let Some(scope_id) = scope_id.get() {
+                let current_scope_id = self.ctx.current_scope_id();
+
+                #[cfg(feature = "tracing")]
+                {
+                    let scopes = self.ctx.scopes();
+                    tracing::trace!(
+                        ?scope_id,
+                        flags = ?scopes.get_flags(scope_id),
+                        node_id = ?scopes.get_node_id(scope_id),
+                        old_parent_id = ?scopes.get_parent_id(scope_id),
+                        new_parent_id = ?current_scope_id,
+                        "reparent scope"
+                    );
+                }
+
+                self.ctx.scopes_mut().change_parent_id(scope_id, Some(current_scope_id));
+            }
+        }
+    }
+
+    /// Handle entering a scope which has no `ScopeId`.
+    ///
+    /// This can only happen if AST was constructed or modified without running semantic on it.
+    /// Rather than panic, skip updating scope tree for this scope, and raise a warning (once only).
+    #[cold]
+    fn enter_scope_without_id(&mut self) {
+        self.missing_scope_id_depth += 1;
+        if !self.found_missing_scope_id {
+            self.found_missing_scope_id = true;
+            self.super_converter.class_properties.ctx.error(OxcDiagnostic::warn(
+                "Scope in static property initializer or static block has no `ScopeId`. Scope tree may be inaccurate after transform.",
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::{AstKind, NONE, ast::*};
+    use oxc_parser::Parser;
+    use oxc_semantic::{NodeId, ScopeFlags, ScopeId, ScopeTree, SemanticBuilder, SymbolTable};
+    use oxc_span::{SPAN, SourceType};
+    use oxc_traverse::{ReusableTraverseCtx, TraverseCtx, traverse_mut};
+
+    use crate::{
+        TransformCtx, TransformOptions,
+        es2022::{ClassProperties, ClassPropertiesOptions},
+    };
+
+    macro_rules! setup {
+        ($ctx:ident) => {
+            let allocator = Allocator::default();
+            let mut scopes = ScopeTree::default();
+            scopes.add_scope(None, NodeId::DUMMY, ScopeFlags::Top);
+            let symbols = SymbolTable::default();
+            let ctx = ReusableTraverseCtx::new(scopes, symbols, &allocator);
+            // SAFETY: Macro user only gets a `&mut TraverseCtx`, which cannot be abused
+            let mut ctx = unsafe { ctx.unwrap() };
+            let $ctx = &mut ctx;
+        };
+    }
+
+    /// Create `() => {}`, with a strict mode scope which is child of root scope
+    fn create_arrow<'a>(ctx: &mut TraverseCtx<'a>) -> (Expression<'a>, ScopeId) {
+        let scope_id = ctx.create_child_scope_of_current(
+            ScopeFlags::Arrow | ScopeFlags::Function | ScopeFlags::StrictMode,
+        );
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            ctx.ast.vec(),
+            NONE,
+        );
+        let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), ctx.ast.vec());
+        let expr = Expression::ArrowFunctionExpression(
+            ctx.ast.alloc_arrow_function_expression_with_scope_id(
+                SPAN, true, false, NONE, params, NONE, body, scope_id,
+            ),
+        );
+        (expr, scope_id)
+    }
+
+    #[test]
+    fn static_initializer_strict() {
+        setup!(ctx);
+        let transform_ctx = TransformCtx::new(Path::new(""), &TransformOptions::default());
+        let mut class_properties =
+            ClassProperties::new(ClassPropertiesOptions::default(), true, &transform_ctx);
+
+        // Root scope is sloppy mode, but is treated as strict mode, so arrow function remains strict mode
+        let (mut expr, scope_id) = create_arrow(ctx);
+        class_properties.transform_static_initializer_with_strictness(&mut expr, true, ctx);
+
+        assert!(matches!(expr, Expression::ArrowFunctionExpression(_)));
+        assert!(ctx.scopes().get_flags(scope_id).is_strict_mode());
+    }
+
+    #[test]
+    fn static_initializer_missing_scope_id() {
+        setup!(ctx);
+        let transform_ctx = TransformCtx::new(Path::new(""), &TransformOptions::default());
+        let mut class_properties =
+            ClassProperties::new(ClassPropertiesOptions::default(), true, &transform_ctx);
+
+        // `function() { return () => {}; }`, where function has no `ScopeId`
+        let (arrow, arrow_scope_id) = create_arrow(ctx);
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec(),
+            NONE,
+        );
+        let stmt = ctx.ast.statement_return(SPAN, Some(arrow));
+        let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), ctx.ast.vec1(stmt));
+        let mut expr = ctx.ast.expression_function(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+        );
+        let Expression::FunctionExpression(func) = &expr else { unreachable!() };
+        assert!(func.scope_id.get().is_none());
+
+        class_properties.transform_static_initializer_with_strictness(&mut expr, false, ctx);
+
+        // Scope with a `ScopeId` inside function is still converted to sloppy mode
+        assert!(!ctx.scopes().get_flags(arrow_scope_id).is_strict_mode());
+
+        let errors = transform_ctx.take_errors();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].to_string().contains("has no `ScopeId`"));
+    }
+
+    #[test]
+    fn static_initializer_declare_field() {
+        setup!(ctx);
+        let transform_ctx = TransformCtx::new(Path::new(""), &TransformOptions::default());
+        let mut class_properties =
+            ClassProperties::new(ClassPropertiesOptions::default(), true, &transform_ctx);
+
+        // `class { declare static x; static y = () => {}; }`.
+        // TypeScript transform removes `declare` fields before this transform runs, but visitor
+        // should skip a field with no value regardless.
+        let class_scope_id = ctx.create_child_scope_of_current(ScopeFlags::StrictMode);
+        let (arrow, arrow_scope_id) = create_arrow(ctx);
+        ctx.scopes_mut().change_parent_id(arrow_scope_id, Some(class_scope_id));
+        let ast = ctx.ast;
+        let create_prop = |name, value, declare| {
+            ast.class_element_property_definition(
+                SPAN,
+                PropertyDefinitionType::PropertyDefinition,
+                ast.vec(),
+                ast.property_key_static_identifier(SPAN, name),
+                value,
+                false,
+                true,
+                declare,
+                false,
+                false,
+                false,
+                false,
+                NONE,
+                None,
+            )
+        };
+        let elements = ctx
+            .ast
+            .vec_from_array([create_prop("x", None, true), create_prop("y", Some(arrow), false)]);
+        let body = ctx.ast.alloc_class_body(SPAN, elements);
+        let mut expr = Expression::ClassExpression(ctx.ast.alloc_class_with_scope_id(
+            SPAN,
+            ClassType::ClassExpression,
+            ctx.ast.vec(),
+            None,
+            NONE,
+            None,
+            NONE,
+            None,
+            body,
+            false,
+            false,
+            class_scope_id,
+        ));
+
+        class_properties.transform_static_initializer_with_strictness(&mut expr, false, ctx);
+
+        let Expression::ClassExpression(class) = &expr else { unreachable!() };
+        let [ClassElement::PropertyDefinition(x), ClassElement::PropertyDefinition(y)] =
+            class.body.body.as_slice()
+        else {
+            unreachable!()
+        };
+        assert!(x.declare && x.value.is_none());
+        assert!(matches!(y.value, Some(Expression::ArrowFunctionExpression(_))));
+        // Class is strict mode, so arrow function inside it remains strict mode
+        assert!(ctx.scopes().get_flags(arrow_scope_id).is_strict_mode());
+        assert_eq!(ctx.scopes().get_parent_id(class_scope_id), Some(ctx.current_scope_id()));
+        assert!(transform_ctx.take_errors().is_empty());
+    }
+
+    #[test]
+    fn static_initializer_sloppy() {
+        setup!(ctx);
+        let transform_ctx = TransformCtx::new(Path::new(""), &TransformOptions::default());
+        let mut class_properties =
+            ClassProperties::new(ClassPropertiesOptions::default(), true, &transform_ctx);
+
+        let (mut expr, scope_id) = create_arrow(ctx);
+        class_properties.transform_static_initializer_with_strictness(&mut expr, false, ctx);
+
+        assert!(matches!(expr, Expression::ArrowFunctionExpression(_)));
+        assert!(!ctx.scopes().get_flags(scope_id).is_strict_mode());
+        assert_eq!(ctx.scopes().get_parent_id(scope_id), Some(ctx.current_scope_id()));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "no class is being transformed")]
+    fn static_initializer_this_without_class() {
+        setup!(ctx);
+        let transform_ctx = TransformCtx::new(Path::new(""), &TransformOptions::default());
+        let mut class_properties =
+            ClassProperties::new(ClassPropertiesOptions::default(), true, &transform_ctx);
+
+        // `this` can't be transformed without class details on `classes_stack`
+        let mut expr = ctx.ast.expression_this(SPAN);
+        class_properties.transform_static_initializer_with_strictness(&mut expr, true, ctx);
+    }
+
+    #[test]
+    fn static_initializer_ts_signature_scopes() {
+        // Class properties transform runs alone, without TypeScript transform, so types are not
+        // removed before static initializers are transformed
+        let source_text = "
+            class C {
+                static x = y as {
+                    m<const T extends C>(a: T, b: typeof C): T;
+                    new <const C>(a: C): C;
+                };
+                static z = () => y as { m<const T>(a: T): typeof C };
+            }
+        ";
+        let allocator = Allocator::default();
+        let mut program = Parser::new(&allocator, source_text, SourceType::ts()).parse().program;
+        let semantic = SemanticBuilder::new().build(&program).semantic;
+        let signature_scope_ids = semantic
+            .nodes()
+            .iter()
+            .filter_map(|node| match node.kind() {
+                AstKind::TSMethodSignature(signature) => signature.scope_id.get(),
+                AstKind::TSConstructSignatureDeclaration(signature) => signature.scope_id.get(),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let [method_scope_id, construct_scope_id, nested_method_scope_id] =
+            signature_scope_ids.as_slice()
+        else {
+            unreachable!()
+        };
+        let nested_parent_scope_id = semantic.scopes().get_parent_id(*nested_method_scope_id);
+        let (symbols, scopes) = semantic.into_symbol_table_and_scope_tree();
+        let root_scope_id = scopes.root_scope_id();
+        let class_symbol_id = scopes.get_root_binding("C").unwrap();
+        let class_reference_ids = symbols.get_resolved_reference_ids(class_symbol_id).to_vec();
+
+        let transform_ctx = TransformCtx::new(Path::new(""), &TransformOptions::default());
+        let mut class_properties =
+            ClassProperties::new(ClassPropertiesOptions::default(), true, &transform_ctx);
+        let (symbols, scopes) =
+            traverse_mut(&mut class_properties, &allocator, &mut program, symbols, scopes);
+
+        // First-level signature scopes are reparented to outside class.
+        // Nested signature's scope remains child of arrow function's scope.
+        assert_eq!(scopes.get_parent_id(*method_scope_id), Some(root_scope_id));
+        assert_eq!(scopes.get_parent_id(*construct_scope_id), Some(root_scope_id));
+        assert_eq!(scopes.get_parent_id(*nested_method_scope_id), nested_parent_scope_id);
+        assert_ne!(nested_parent_scope_id, Some(root_scope_id));
+
+        // References to class name and type parameters within signatures are types,
+        // so are not replaced with temp var for class, and no temp var is created
+        for reference_id in class_reference_ids {
+            assert_eq!(symbols.get_reference(reference_id).symbol_id(), Some(class_symbol_id));
         }
+        assert!(scopes.get_root_binding("_C").is_none());
+        assert!(transform_ctx.take_errors().is_empty());
     }
 }
@@ -0,0 +1,51 @@
+//! ES2022: Class Properties
+//! Statistics on traversal of static property initializers and static blocks.
+//!
+//! Only compiled with `transform_stats` feature.
+
+use std::cell::Cell;
+
+thread_local! {
+    static STATS: Cell<ClassPropertiesStats> = const { Cell::new(ClassPropertiesStats::new()) };
+}
+
+/// Counts of how static initializers / static blocks were traversed on the current thread.
+///
+/// Used for profiling, to quantify how often full traversal of initializers is avoided.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ClassPropertiesStats {
+    /// Number of static initializers / static blocks which were traversed shallowly
+    /// (only as far as functions and other constructs which define a `this`).
+    pub shallow: u32,
+    /// Number of static initializers / static blocks which were traversed fully.
+    pub deep: u32,
+}
+
+impl ClassPropertiesStats {
+    const fn new() -> Self {
+        Self { shallow: 0, deep: 0 }
+    }
+
+    /// Get counts for current thread.
+    pub fn get() -> Self {
+        STATS.get()
+    }
+
+    /// Reset counts for current thread to zero.
+    pub fn reset() {
+        STATS.set(Self::new());
+    }
+
+    /// Record a traversal of a static initializer / static block.
+    pub(super) fn record(walk_deep: bool) {
+        STATS.with(|stats| {
+            let mut counts = stats.get();
+            if walk_deep {
+                counts.deep += 1;
+            } else {
+                counts.shallow += 1;
+            }
+            stats.set(counts);
+        });
+    }
+}
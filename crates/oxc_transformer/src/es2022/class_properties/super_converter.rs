@@ -2,8 +2,12 @@
 //! Transform of `super` expressions.
 
 use oxc_allocator::{Box as ArenaBox, Vec as ArenaVec};
-use oxc_ast::ast::*;
+use oxc_ast::{NONE, ast::*};
 use oxc_span::SPAN;
+use oxc_syntax::{
+    reference::ReferenceFlags,
+    scope::{ScopeFlags, ScopeId},
+};
 use oxc_traverse::{TraverseCtx, ast_operations::get_var_name_from_node};
 
 use crate::{Helper, utils::ast_builder::create_prototype_member};
@@ -21,6 +25,10 @@ pub(super) enum ClassPropertiesSuperConverterMode {
 }
 
 /// Convert `super` expressions.
+///
+/// Conversion is not gated on target. `super` is only valid inside methods and class bodies, and
+/// code being transformed is moved outside of the method or class body, so `super` must always
+/// be converted. On targets which support class fields, this transform does not run at all.
 pub(super) struct ClassPropertiesSuperConverter<'a, 'ctx, 'v> {
     mode: ClassPropertiesSuperConverterMode,
     pub(super) class_properties: &'v mut ClassProperties<'a, 'ctx>,
@@ -116,6 +124,26 @@ impl<'a> ClassPropertiesSuperConverter<'a, '_, '_> {
         };
     }
 
+    /// Transform optional call expression where callee is a member expression with `super`.
+    ///
+    /// `super.method?.()` -> `_superPropGet(_Class, "method", _Class, 2)?.([])`
+    /// `super[method]?.(1)` -> `_superPropGet(_Class, method, _Class, 2)?.([1])`
+    ///
+    /// `_superPropGet` only wraps the method in a function if it is a function, so `?.()` still
+    /// short-circuits if method is `null` or `undefined`.
+    //
+    // `#[inline]` for same reason as `transform_call_expression_for_super_member_expr`.
+    #[inline]
+    pub(super) fn transform_chain_expression_for_super_member_call(
+        &mut self,
+        chain_expr: &mut ChainExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let ChainElement::CallExpression(call_expr) = &mut chain_expr.expression {
+            self.transform_call_expression_for_super_member_expr(call_expr, ctx);
+        }
+    }
+
     fn transform_call_expression_for_super_static_member_expr(
         &mut self,
         call_expr: &mut CallExpression<'a>,
@@ -541,6 +569,110 @@ impl<'a> ClassPropertiesSuperConverter<'a, '_, '_> {
         }
     }
 
+    /// Transform `delete` unary expression where argument is a member expression with `super`.
+    ///
+    /// Deleting a property of `super` is always a `ReferenceError` at runtime,
+    /// so replace with an IIFE which throws.
+    ///
+    /// * `delete super.prop`
+    ///   -> `function() { throw new ReferenceError("'delete super.prop' is invalid"); }()`
+    /// * `delete super[prop]`
+    ///   -> `(_toPropertyKey(prop), function() { throw new ReferenceError("'delete super[expr]' is invalid"); }())`
+    ///
+    /// `scope_id` is the scope which the `delete` expression is in.
+    /// Scope for the new function is created as a child of it.
+    ///
+    /// Property key of `super[prop]` is not visited. Caller must visit it before calling this method.
+    pub(super) fn transform_unary_expression_for_super_member_expr(
+        &self,
+        expr: &mut Expression<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::UnaryExpression(unary_expr) = expr else { unreachable!() };
+        let span = unary_expr.span;
+        match &mut unary_expr.argument {
+            Expression::StaticMemberExpression(member) if member.object.is_super() => {
+                *expr = Self::create_throw_reference_error_iife(
+                    span,
+                    "'delete super.prop' is invalid",
+                    scope_id,
+                    ctx,
+                );
+            }
+            Expression::ComputedMemberExpression(member) if member.object.is_super() => {
+                let property = ctx.ast.move_expression(&mut member.expression);
+                let to_property_key = self.class_properties.ctx.helper_call_expr(
+                    Helper::ToPropertyKey,
+                    SPAN,
+                    ctx.ast.vec1(Argument::from(property)),
+                    ctx,
+                );
+                let iife = Self::create_throw_reference_error_iife(
+                    SPAN,
+                    "'delete super[expr]' is invalid",
+                    scope_id,
+                    ctx,
+                );
+                *expr = ctx
+                    .ast
+                    .expression_sequence(span, ctx.ast.vec_from_array([to_property_key, iife]));
+            }
+            _ => {}
+        }
+    }
+
+    /// `function() { throw new ReferenceError(message); }()`
+    fn create_throw_reference_error_iife(
+        span: Span,
+        message: &'static str,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let func_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::Function);
+
+        // `new ReferenceError(message)`
+        let symbol_id = ctx.scopes().find_binding(scope_id, "ReferenceError");
+        let callee = ctx.create_ident_expr(
+            SPAN,
+            Atom::from("ReferenceError"),
+            symbol_id,
+            ReferenceFlags::Read,
+        );
+        let message = ctx.ast.expression_string_literal(SPAN, message, None);
+        let error =
+            ctx.ast.expression_new(SPAN, callee, ctx.ast.vec1(Argument::from(message)), NONE);
+
+        // `function() { throw new ReferenceError(message); }`
+        let body = ctx.ast.alloc_function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec1(ctx.ast.statement_throw(SPAN, error)),
+        );
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec(),
+            NONE,
+        );
+        let func = Expression::FunctionExpression(ctx.ast.alloc_function_with_scope_id(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+            func_scope_id,
+        ));
+
+        ctx.ast.expression_call(span, func, NONE, ctx.ast.vec(), false)
+    }
+
     /// Member:
     ///  `_superPropGet(_Class, prop, _Class)`
     ///
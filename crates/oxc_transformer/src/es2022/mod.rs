@@ -1,5 +1,7 @@
 use oxc_ast::ast::*;
+use oxc_semantic::{ScopeId, ScopeTree, SymbolId};
 use oxc_traverse::{Traverse, TraverseCtx};
+use rustc_hash::FxHashMap;
 
 use crate::TransformCtx;
 
@@ -8,7 +10,9 @@ mod class_static_block;
 mod options;
 
 use class_properties::ClassProperties;
-pub use class_properties::ClassPropertiesOptions;
+#[cfg(feature = "transform_stats")]
+pub use class_properties::ClassPropertiesStats;
+pub use class_properties::{ClassPropertiesOptions, StaticThisReplacement};
 use class_static_block::ClassStaticBlock;
 pub use options::ES2022Options;
 
@@ -34,9 +38,75 @@ impl<'a, 'ctx> ES2022<'a, 'ctx> {
             };
         Self { class_static_block, class_properties }
     }
+
+    /// Use temp vars created by a previous pass for classes, if class properties transform is enabled.
+    ///
+    /// See [`ClassProperties::adopt_temp_bindings`].
+    pub fn adopt_class_temp_bindings(&mut self, bindings: FxHashMap<ScopeId, SymbolId>) {
+        if let Some(class_properties) = self.class_properties.as_mut() {
+            class_properties.adopt_temp_bindings(bindings);
+        }
+    }
+
+    /// Provide strictness of code outside classes, if class properties transform is enabled.
+    ///
+    /// See [`ClassProperties::set_static_initializer_strictness`].
+    pub fn set_static_initializer_strictness(&mut self, strictness: FxHashMap<ScopeId, bool>) {
+        if let Some(class_properties) = self.class_properties.as_mut() {
+            class_properties.set_static_initializer_strictness(strictness);
+        }
+    }
+
+    /// Provide callback to replace `this` in static initializers, if class properties transform is enabled.
+    ///
+    /// See [`StaticThisReplacement`].
+    pub fn set_static_this_replacement(&mut self, replacement: StaticThisReplacement<'a>) {
+        if let Some(class_properties) = self.class_properties.as_mut() {
+            class_properties.set_static_this_replacement(replacement);
+        }
+    }
+
+    /// Take temp vars used for classes by class properties transform.
+    ///
+    /// See [`ClassProperties::take_temp_bindings`].
+    pub fn take_class_temp_bindings(&mut self) -> FxHashMap<ScopeId, SymbolId> {
+        self.class_properties
+            .as_mut()
+            .map_or_else(FxHashMap::default, ClassProperties::take_temp_bindings)
+    }
+
+    /// Returns `true` if class properties transform will walk static property initializers /
+    /// static blocks of `class` in full. Returns `false` if class properties transform is disabled.
+    ///
+    /// See [`ClassProperties::will_walk_deep`].
+    ///
+    /// `static_initializer_strictness` is strictness of code outside class provided by caller, if any.
+    /// See [`ClassProperties::set_static_initializer_strictness`].
+    pub fn class_properties_will_walk_deep(
+        options: &ES2022Options,
+        class: &Class<'a>,
+        scopes: &ScopeTree,
+        static_initializer_strictness: Option<bool>,
+    ) -> bool {
+        options.class_properties.as_ref().is_some_and(|properties_options| {
+            ClassProperties::will_walk_deep(
+                properties_options,
+                options.class_static_block,
+                class,
+                scopes,
+                static_initializer_strictness,
+            )
+        })
+    }
 }
 
 impl<'a> Traverse<'a> for ES2022<'a, '_> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(class_properties) = &mut self.class_properties {
+            class_properties.enter_program(program, ctx);
+        }
+    }
+
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(class_properties) = &mut self.class_properties {
             class_properties.enter_expression(expr, ctx);
@@ -10,7 +10,7 @@ use std::path::Path;
 use oxc_allocator::{Allocator, Vec as ArenaVec};
 use oxc_ast::{AstBuilder, ast::*};
 use oxc_diagnostics::OxcDiagnostic;
-use oxc_semantic::{ScopeTree, SymbolTable};
+use oxc_semantic::{ScopeId, ScopeTree, SymbolId, SymbolTable};
 use oxc_span::SPAN;
 use oxc_traverse::{Traverse, TraverseCtx, traverse_mut};
 
@@ -58,6 +58,7 @@ pub use crate::{
     compiler_assumptions::CompilerAssumptions,
     decorator::DecoratorOptions,
     es2015::{ArrowFunctionsOptions, ES2015Options},
+    es2022::StaticThisReplacement,
     jsx::{JsxOptions, JsxRuntime, ReactRefreshOptions},
     options::{
         ESTarget, Engine, EngineTargets, EnvOptions, Module, TransformOptions,
@@ -67,11 +68,19 @@ pub use crate::{
     typescript::{RewriteExtensionsMode, TypeScriptOptions},
 };
 
+#[cfg(feature = "transform_stats")]
+pub use crate::es2022::ClassPropertiesStats;
+
 #[non_exhaustive]
 pub struct TransformerReturn {
     pub errors: std::vec::Vec<OxcDiagnostic>,
     pub symbols: SymbolTable,
     pub scopes: ScopeTree,
+    /// Temp vars created by class properties transform for classes (e.g. `_C` in `_C = class C {}`),
+    /// keyed by `ScopeId` of the class. Temp vars are declared in hoist scope outside the class.
+    ///
+    /// Can be passed to [`Transformer::with_class_temp_bindings`] for a later pass over same AST.
+    pub class_temp_bindings: FxHashMap<ScopeId, SymbolId>,
     /// Helpers used by this transform.
     #[deprecated = "Internal usage only"]
     pub helpers_used: FxHashMap<Helper, String>,
@@ -86,6 +95,15 @@ pub struct Transformer<'a> {
     decorator: DecoratorOptions,
     jsx: JsxOptions,
     env: EnvOptions,
+
+    /// Temp vars for classes created by a previous pass. See [`Transformer::with_class_temp_bindings`].
+    class_temp_bindings: FxHashMap<ScopeId, SymbolId>,
+
+    /// Strictness of code outside classes. See [`Transformer::with_static_initializer_strictness`].
+    static_initializer_strictness: FxHashMap<ScopeId, bool>,
+
+    /// Callback to replace `this` in static initializers. See [`Transformer::with_static_this_replacement`].
+    static_this_replacement: Option<StaticThisReplacement<'a>>,
 }
 
 impl<'a> Transformer<'a> {
@@ -98,7 +116,54 @@ impl<'a> Transformer<'a> {
             decorator: options.decorator,
             jsx: options.jsx.clone(),
             env: options.env,
-        }
+            class_temp_bindings: FxHashMap::default(),
+            static_initializer_strictness: FxHashMap::default(),
+            static_this_replacement: None,
+        }
+    }
+
+    /// Use temp vars which a previous pass created for classes, instead of class properties transform
+    /// creating new ones. Avoids producing both `_C` and `_C2` for the same class.
+    ///
+    /// `bindings` maps `ScopeId` of a class to `SymbolId` of its temp var. Temp var must already be
+    /// declared (e.g. `var _C;`) in a scope where it's accessible from outside the class.
+    /// It's used only if class properties transform requires a temp var for the class.
+    ///
+    /// Ignored for anonymous `export default class {}`, whose temp var is also used as class's name.
+    #[must_use]
+    pub fn with_class_temp_bindings(mut self, bindings: FxHashMap<ScopeId, SymbolId>) -> Self {
+        self.class_temp_bindings = bindings;
+        self
+    }
+
+    /// Provide strictness of code outside classes, instead of class properties transform obtaining it
+    /// from flags of the scope outside the class.
+    ///
+    /// `strictness` maps `ScopeId` of a class to `true` if code outside it is strict mode.
+    /// It determines whether the class's static property initializers are converted to sloppy mode
+    /// (or wrapped in a strict mode IIFE) when they're moved out of the class.
+    /// Static blocks and other parts of the class are unaffected.
+    ///
+    /// Caller is responsible for strictness being correct. If it's not, output may behave differently
+    /// from the input.
+    #[must_use]
+    pub fn with_static_initializer_strictness(
+        mut self,
+        strictness: FxHashMap<ScopeId, bool>,
+    ) -> Self {
+        self.static_initializer_strictness = strictness;
+        self
+    }
+
+    /// Provide callback to replace `this` in static property initializers and static blocks
+    /// when class properties transform moves them out of the class, instead of replacing it with
+    /// class temp var.
+    ///
+    /// See [`StaticThisReplacement`] for details, and the contract the callback must uphold.
+    #[must_use]
+    pub fn with_static_this_replacement(mut self, replacement: StaticThisReplacement<'a>) -> Self {
+        self.static_this_replacement = Some(replacement);
+        self
     }
 
     pub fn build_with_symbols_and_scopes(
@@ -138,10 +203,40 @@ impl<'a> Transformer<'a> {
             x4_regexp: RegExp::new(self.env.regexp, &self.ctx),
         };
 
+        transformer.x2_es2022.adopt_class_temp_bindings(self.class_temp_bindings);
+        transformer.x2_es2022.set_static_initializer_strictness(self.static_initializer_strictness);
+        if let Some(replacement) = self.static_this_replacement {
+            transformer.x2_es2022.set_static_this_replacement(replacement);
+        }
+
         let (symbols, scopes) = traverse_mut(&mut transformer, allocator, program, symbols, scopes);
+        let class_temp_bindings = transformer.x2_es2022.take_class_temp_bindings();
         let helpers_used = self.ctx.helper_loader.used_helpers.borrow_mut().drain().collect();
         #[expect(deprecated)]
-        TransformerReturn { errors: self.ctx.take_errors(), symbols, scopes, helpers_used }
+        TransformerReturn {
+            errors: self.ctx.take_errors(),
+            symbols,
+            scopes,
+            class_temp_bindings,
+            helpers_used,
+        }
+    }
+
+    /// Returns `true` if class properties transform will walk static property initializers and
+    /// static blocks of `class` in full, rather than only as far as nested functions.
+    /// Returns `false` if class properties transform is not enabled.
+    ///
+    /// Walk is deep if class has a name, or code outside class is sloppy mode.
+    /// `scopes` is the `ScopeTree` which will be passed to [`Transformer::build_with_symbols_and_scopes`].
+    pub fn class_properties_will_walk_deep(&self, class: &Class<'a>, scopes: &ScopeTree) -> bool {
+        let static_initializer_strictness =
+            self.static_initializer_strictness.get(&class.scope_id()).copied();
+        ES2022::class_properties_will_walk_deep(
+            &self.env.es2022,
+            class,
+            scopes,
+            static_initializer_strictness,
+        )
     }
 }
 
@@ -169,6 +264,7 @@ impl<'a> Traverse<'a> for TransformerImpl<'a, '_> {
             typescript.enter_program(program, ctx);
         }
         self.x1_jsx.enter_program(program, ctx);
+        self.x2_es2022.enter_program(program, ctx);
     }
 
     fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
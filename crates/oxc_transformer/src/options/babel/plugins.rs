@@ -19,6 +19,17 @@ pub struct SyntaxDecoratorOptions {
     pub version: String,
 }
 
+/// Options for `transform-class-properties` plugin.
+///
+/// `tempVarPrefix` is not a [`ClassPropertiesOptions`] field, as it's stored in `TransformOptions`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct ClassPropertiesPluginOptions {
+    #[serde(flatten)]
+    options: ClassPropertiesOptions,
+    temp_var_prefix: Option<String>,
+}
+
 #[derive(Debug, Default, Clone, Deserialize)]
 #[serde(try_from = "PluginPresetEntries")]
 pub struct BabelPlugins {
@@ -69,6 +80,7 @@ pub struct BabelPlugins {
     // ES2022
     pub class_static_block: bool,
     pub class_properties: Option<ClassPropertiesOptions>,
+    pub class_temp_var_prefix: Option<String>,
 
     // Decorator
     pub legacy_decorator: Option<DecoratorOptions>,
@@ -136,10 +148,13 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                 "transform-logical-assignment-operators" => p.logical_assignment_operators = true,
                 "transform-class-static-block" => p.class_static_block = true,
                 "transform-class-properties" => {
-                    p.class_properties = entry
-                        .value::<ClassPropertiesOptions>()
+                    if let Ok(plugin_options) = entry
+                        .value::<ClassPropertiesPluginOptions>()
                         .map_err(|err| p.errors.push(err))
-                        .ok();
+                    {
+                        p.class_properties = Some(plugin_options.options);
+                        p.class_temp_var_prefix = plugin_options.temp_var_prefix;
+                    }
                 }
                 // This is not a Babel plugin, we pretend it exists for running legacy decorator by Babel options
                 "transform-legacy-decorator" => {
@@ -65,6 +65,11 @@ pub struct TransformOptions {
     /// ECMAScript Env Options
     pub env: EnvOptions,
 
+    /// Prefix for name of temp var created for class by class properties transform.
+    /// e.g. with prefix `"cls$"`, temp var for `class C {}` is `_cls$C`, instead of `_C`.
+    /// Name is still de-duplicated against all other bindings, so is always unique.
+    pub class_temp_var_prefix: Option<String>,
+
     pub helper_loader: HelperLoaderOptions,
 }
 
@@ -85,6 +90,7 @@ impl TransformOptions {
                 ..JsxOptions::default()
             },
             env: EnvOptions::enable_all(/* include_unfinished_plugins */ false),
+            class_temp_var_prefix: None,
             helper_loader: HelperLoaderOptions {
                 mode: HelperLoaderMode::Runtime,
                 ..Default::default()
@@ -271,6 +277,7 @@ impl TryFrom<&BabelOptions> for TransformOptions {
                 es2021,
                 es2022,
             },
+            class_temp_var_prefix: options.plugins.class_temp_var_prefix.clone(),
             helper_loader,
         })
     }
@@ -1,7 +1,9 @@
 use rustc_hash::FxHashSet;
 
+use std::cell::Cell;
+
 use oxc_allocator::Vec as ArenaVec;
-use oxc_ast::ast::*;
+use oxc_ast::{Visit, ast::*, visit::walk};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_semantic::SymbolFlags;
 use oxc_span::{Atom, GetSpan, SPAN, Span};
@@ -61,6 +63,15 @@ impl<'a, 'ctx> TypeScriptAnnotations<'a, 'ctx> {
 }
 
 impl<'a> Traverse<'a> for TypeScriptAnnotations<'a, '_> {
+    /// Delete references and scopes within TypeScript types, as all types are removed by this transform.
+    ///
+    /// Types are removed in many different places, so this is done in a single pass over the program
+    /// before transform, rather than at each place a type is removed.
+    /// `let x: typeof y = z as typeof y;` -> `let x = z;` - both references to `y` are deleted.
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        TypeSemanticDataRemover { type_depth: 0, ctx }.visit_program(program);
+    }
+
     fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         let mut no_modules_remaining = true;
         let mut some_modules_deleted = false;
@@ -624,6 +635,88 @@ impl<'a> TypeScriptAnnotations<'a, '_> {
     }
 }
 
+/// Visitor which deletes references and scopes within TypeScript types.
+///
+/// References to value bindings in types (`typeof y`) are recorded as resolved references
+/// of those bindings, and types with scopes (`type T<U> = U`, `(x: number) => void`)
+/// are recorded as child scopes of their parent. Neither exist once types are removed.
+///
+/// Symbols declared within types are left in `SymbolTable`, but bindings for them are removed
+/// by `TraverseScoping::delete_typescript_bindings` at end of transform.
+struct TypeSemanticDataRemover<'a, 'ctx> {
+    type_depth: u32,
+    ctx: &'ctx mut TraverseCtx<'a>,
+}
+
+impl TypeSemanticDataRemover<'_, '_> {
+    fn enter_type(&mut self) {
+        self.type_depth += 1;
+    }
+
+    fn exit_type(&mut self) {
+        self.type_depth -= 1;
+    }
+}
+
+impl<'a> Visit<'a> for TypeSemanticDataRemover<'a, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if self.type_depth > 0 {
+            self.ctx.scopes_mut().delete_scope(scope_id.get().unwrap());
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if self.type_depth > 0 {
+            self.ctx.delete_reference_for_identifier(ident);
+        }
+    }
+
+    fn visit_ts_type(&mut self, ty: &TSType<'a>) {
+        self.enter_type();
+        walk::walk_ts_type(self, ty);
+        self.exit_type();
+    }
+
+    fn visit_ts_type_annotation(&mut self, annotation: &TSTypeAnnotation<'a>) {
+        self.enter_type();
+        walk::walk_ts_type_annotation(self, annotation);
+        self.exit_type();
+    }
+
+    fn visit_ts_type_parameter_declaration(&mut self, decl: &TSTypeParameterDeclaration<'a>) {
+        self.enter_type();
+        walk::walk_ts_type_parameter_declaration(self, decl);
+        self.exit_type();
+    }
+
+    fn visit_ts_type_parameter_instantiation(
+        &mut self,
+        instantiation: &TSTypeParameterInstantiation<'a>,
+    ) {
+        self.enter_type();
+        walk::walk_ts_type_parameter_instantiation(self, instantiation);
+        self.exit_type();
+    }
+
+    fn visit_ts_type_alias_declaration(&mut self, decl: &TSTypeAliasDeclaration<'a>) {
+        self.enter_type();
+        walk::walk_ts_type_alias_declaration(self, decl);
+        self.exit_type();
+    }
+
+    fn visit_ts_interface_declaration(&mut self, decl: &TSInterfaceDeclaration<'a>) {
+        self.enter_type();
+        walk::walk_ts_interface_declaration(self, decl);
+        self.exit_type();
+    }
+
+    fn visit_ts_class_implements(&mut self, implements: &TSClassImplements<'a>) {
+        self.enter_type();
+        walk::walk_ts_class_implements(self, implements);
+        self.exit_type();
+    }
+}
+
 struct Assignment<'a> {
     span: Span,
     name: Atom<'a>,
@@ -72,6 +72,7 @@ impl<'a> Traverse<'a> for TypeScript<'a, '_> {
             program.body.clear();
         } else {
             program.source_type = program.source_type.with_javascript(true);
+            self.annotations.enter_program(program, ctx);
             self.namespace.enter_program(program, ctx);
         }
     }
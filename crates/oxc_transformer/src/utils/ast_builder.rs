@@ -46,11 +46,43 @@ pub fn wrap_expression_in_arrow_function_iife<'a>(
     expr: Expression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) -> Expression<'a> {
-    let scope_id =
-        ctx.insert_scope_below_expression(&expr, ScopeFlags::Arrow | ScopeFlags::Function);
+    create_iife(expr, false, false, ctx)
+}
+
+/// Wrap an `Expression` in an arrow function IIFE (immediately invoked function expression)
+/// with a body block, which is optionally async and/or strict mode.
+///
+/// * `expr` -> `(() => { return expr; })()`
+/// * `expr` -> `(async () => { return expr; })()` (`is_async == true`)
+/// * `expr` -> `(() => { "use strict"; return expr; })()` (`is_strict == true`)
+///
+/// `expr` must be in current scope. Scope for the arrow function is created as a child of current scope,
+/// and any scopes in `expr` which were children of current scope are reparented to be children of it.
+pub fn create_iife<'a>(
+    expr: Expression<'a>,
+    is_async: bool,
+    is_strict: bool,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let mut flags = ScopeFlags::Arrow | ScopeFlags::Function;
+    if is_strict {
+        flags |= ScopeFlags::StrictMode;
+    }
+    let scope_id = ctx.insert_scope_below_expression(&expr, flags);
+
+    let directives = if is_strict {
+        ctx.ast.vec1(ctx.ast.directive(
+            SPAN,
+            ctx.ast.string_literal(SPAN, "use strict", None),
+            Atom::from("use strict"),
+        ))
+    } else {
+        ctx.ast.vec()
+    };
+
     let span = expr.span();
     let stmts = ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(expr)));
-    wrap_statements_in_arrow_function_iife(stmts, scope_id, span, ctx)
+    create_arrow_function_iife(directives, stmts, is_async, scope_id, span, ctx)
 }
 
 /// Wrap statements in an IIFE (immediately invoked function expression).
@@ -61,13 +93,25 @@ pub fn wrap_statements_in_arrow_function_iife<'a>(
     scope_id: ScopeId,
     span: Span,
     ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    create_arrow_function_iife(ctx.ast.vec(), stmts, false, scope_id, span, ctx)
+}
+
+/// `(() => { <directives> <stmts> })()`
+fn create_arrow_function_iife<'a>(
+    directives: ArenaVec<'a, Directive<'a>>,
+    stmts: ArenaVec<'a, Statement<'a>>,
+    is_async: bool,
+    scope_id: ScopeId,
+    span: Span,
+    ctx: &TraverseCtx<'a>,
 ) -> Expression<'a> {
     let kind = FormalParameterKind::ArrowFormalParameters;
     let params = ctx.ast.alloc_formal_parameters(SPAN, kind, ctx.ast.vec(), NONE);
-    let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), stmts);
+    let body = ctx.ast.alloc_function_body(SPAN, directives, stmts);
     let arrow =
         Expression::ArrowFunctionExpression(ctx.ast.alloc_arrow_function_expression_with_scope_id(
-            SPAN, false, false, NONE, params, NONE, body, scope_id,
+            SPAN, false, is_async, NONE, params, NONE, body, scope_id,
         ));
     ctx.ast.expression_call(span, arrow, NONE, ctx.ast.vec(), false)
 }
@@ -91,3 +135,86 @@ pub fn create_property_access<'a>(
     let property = ctx.ast.identifier_name(SPAN, ctx.ast.atom(property));
     Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
 }
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{NONE, ast::*};
+    use oxc_semantic::{NodeId, ScopeFlags, ScopeTree, SymbolTable};
+    use oxc_span::SPAN;
+    use oxc_traverse::ReusableTraverseCtx;
+
+    use super::create_iife;
+
+    macro_rules! setup {
+        ($ctx:ident) => {
+            let allocator = Allocator::default();
+            let mut scopes = ScopeTree::default();
+            scopes.add_scope(None, NodeId::DUMMY, ScopeFlags::Top);
+            let symbols = SymbolTable::default();
+            let ctx = ReusableTraverseCtx::new(scopes, symbols, &allocator);
+            // SAFETY: Macro user only gets a `&mut TraverseCtx`, which cannot be abused
+            let mut ctx = unsafe { ctx.unwrap() };
+            let $ctx = &mut ctx;
+        };
+    }
+
+    #[test]
+    fn iife_scopes() {
+        setup!(ctx);
+
+        // `() => {}`, with scope which is child of root scope
+        let root_scope_id = ctx.current_scope_id();
+        let inner_scope_id =
+            ctx.create_child_scope_of_current(ScopeFlags::Arrow | ScopeFlags::Function);
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            ctx.ast.vec(),
+            NONE,
+        );
+        let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), ctx.ast.vec());
+        let expr = Expression::ArrowFunctionExpression(
+            ctx.ast.alloc_arrow_function_expression_with_scope_id(
+                SPAN,
+                false,
+                false,
+                NONE,
+                params,
+                NONE,
+                body,
+                inner_scope_id,
+            ),
+        );
+
+        let iife = create_iife(expr, false, false, ctx);
+
+        let Expression::CallExpression(call) = &iife else { panic!() };
+        let Expression::ArrowFunctionExpression(arrow) = &call.callee else { panic!() };
+        let iife_scope_id = arrow.scope_id();
+        assert!(!arrow.r#async);
+        assert!(arrow.body.directives.is_empty());
+        assert_eq!(ctx.scopes().get_parent_id(iife_scope_id), Some(root_scope_id));
+        assert_eq!(ctx.scopes().get_parent_id(inner_scope_id), Some(iife_scope_id));
+        assert!(!ctx.scopes().get_flags(iife_scope_id).is_strict_mode());
+    }
+
+    #[test]
+    fn iife_strict_async() {
+        setup!(ctx);
+
+        let expr = ctx.ast.expression_identifier(SPAN, "x");
+        let iife = create_iife(expr, true, true, ctx);
+
+        let Expression::CallExpression(call) = &iife else { panic!() };
+        let Expression::ArrowFunctionExpression(arrow) = &call.callee else { panic!() };
+        assert!(arrow.r#async);
+        assert_eq!(arrow.body.directives.len(), 1);
+        assert!(arrow.body.directives[0].is_use_strict());
+        assert!(matches!(arrow.body.statements.as_slice(), [Statement::ReturnStatement(_)]));
+
+        let flags = ctx.scopes().get_flags(arrow.scope_id());
+        assert!(flags.contains(ScopeFlags::Arrow | ScopeFlags::Function | ScopeFlags::StrictMode));
+        assert_eq!(ctx.scopes().get_parent_id(arrow.scope_id()), Some(ctx.current_scope_id()));
+    }
+}
@@ -0,0 +1,1022 @@
+use std::path::Path;
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    Visit,
+    ast::{Class, Expression, IdentifierReference, Statement},
+};
+use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_parser::{ParseOptions, Parser};
+use oxc_semantic::{ScopeTree, SemanticBuilder};
+use oxc_span::SourceType;
+use oxc_syntax::scope::{ScopeFlags, ScopeId};
+use oxc_transformer::{BabelOptions, ESTarget, TransformOptions, Transformer};
+
+use crate::{codegen, test};
+
+#[test]
+fn static_prop_with_statement() {
+    // `with` is a syntax error in class body, but transform should not panic if it's run regardless
+    let case = "class C { static x = (function() { with (o) { y; } })(); }";
+    let options = TransformOptions::from(ESTarget::ES2021);
+    let expected = "import _defineProperty from '@oxc-project/runtime/helpers/defineProperty';
+class C {}
+_defineProperty(C, 'x', function() {
+\twith(o) {
+\t\ty;
+\t}
+}());
+";
+    assert_eq!(test(case, &options), Ok(expected.to_string()));
+}
+
+#[test]
+fn static_block_await() {
+    // `await` in static block is a syntax error. Transform does not report it (semantic does),
+    // but should not panic if it's run regardless. `await` is left as is, and `this` is transformed.
+    let case =
+        "class C { static { await this.init(); } static { let x = 1; await this.init(x); } }";
+
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, case, SourceType::mjs()).parse();
+    let semantic_ret = SemanticBuilder::new().with_check_syntax_error(true).build(&ret.program);
+    assert_eq!(semantic_ret.errors.len(), 2);
+    assert!(semantic_ret.errors[0].to_string().contains("await"));
+
+    let options = TransformOptions::from(ESTarget::ES2021);
+    let expected = "var _C;
+class C {}
+_C = C;
+await _C.init();
+(() => {
+\tlet x = 1;
+\tawait _C.init(x);
+})();
+";
+    assert_eq!(test(case, &options), Ok(expected.to_string()));
+}
+
+#[test]
+fn recovered_class_elements() {
+    // Class elements which fail to parse are dropped when parser recovers from errors in class
+    // elements. Transform should not panic on the recovered AST.
+    let case = "
+        class C {
+            a = 1 b = 2;
+            static c = ;
+            static d = this;
+            method() { x y }
+            static [e] = C;
+            static #f = (1 2)
+            static g() { return C.#h; }
+            static #h = 3;
+        }
+    ";
+
+    let allocator = Allocator::default();
+    let parse_options = ParseOptions { recover_class_elements: true, ..ParseOptions::default() };
+    let ret = Parser::new(&allocator, case, SourceType::mjs()).with_options(parse_options).parse();
+    assert!(!ret.panicked);
+    assert_eq!(ret.errors.len(), 4);
+    let mut program = ret.program;
+
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    let options = TransformOptions::from(ESTarget::ES2021);
+    let ret = Transformer::new(&allocator, Path::new(""), &options).build_with_symbols_and_scopes(
+        symbols,
+        scopes,
+        &mut program,
+    );
+    assert!(ret.errors.is_empty());
+
+    let code = CodeGenerator::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code;
+    let expected = "import _defineProperty from '@oxc-project/runtime/helpers/defineProperty';
+var _C;
+let _e;
+_e = e;
+class C {
+\tconstructor() {
+\t\t_defineProperty(this, 'b', 2);
+\t}
+\tstatic g() {
+\t\treturn _h._;
+\t}
+}
+_C = C;
+_defineProperty(C, 'd', _C);
+_defineProperty(C, _e, _C);
+var _h = { _: 3 };
+";
+    assert_eq!(code, expected);
+}
+
+#[test]
+fn static_prop_arguments() {
+    let options = TransformOptions::from(ESTarget::ES2021);
+
+    // `arguments` in nested function is fine
+    let case = "class C { static x = function() { return arguments; }; }";
+    assert!(test(case, &options).is_ok());
+
+    // `arguments` in static initializer or static block is an error
+    for case in [
+        "class C { static x = arguments; }",
+        "class C { static x = () => arguments; }",
+        "class C { static x = 1; static { arguments; } }",
+    ] {
+        let errors = test(case, &options).unwrap_err();
+        assert_eq!(errors.len(), 1, "{case}");
+        assert!(errors[0].to_string().contains("'arguments' is not allowed"), "{case}");
+    }
+}
+
+#[test]
+fn static_prop_module_scopes_remain_strict() {
+    use oxc_ast::AstKind;
+
+    // Scopes in static initializers and static blocks are moved out of class body. In ES module,
+    // code outside class is also strict mode, so scopes remain strict. In sloppy mode script,
+    // they're converted to sloppy mode.
+    let source_text = "class C { static x = () => this; static { let f = function() {}; f(); } }";
+    for (source_type, is_strict) in
+        [(SourceType::mjs(), true), (SourceType::cjs(), false), (SourceType::unambiguous(), false)]
+    {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let mut program = ret.program;
+        let semantic = SemanticBuilder::new().build(&program).semantic;
+        let scope_ids = semantic
+            .nodes()
+            .iter()
+            .filter_map(|node| match node.kind() {
+                AstKind::ArrowFunctionExpression(arrow) => arrow.scope_id.get(),
+                AstKind::Function(func) => func.scope_id.get(),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(scope_ids.len(), 2);
+        assert!(
+            scope_ids
+                .iter()
+                .all(|&scope_id| semantic.scopes().get_flags(scope_id).is_strict_mode())
+        );
+        let (symbols, scopes) = semantic.into_symbol_table_and_scope_tree();
+        let options = TransformOptions::from(ESTarget::ES2021);
+        let ret = Transformer::new(&allocator, Path::new(""), &options)
+            .build_with_symbols_and_scopes(symbols, scopes, &mut program);
+        assert!(ret.errors.is_empty());
+
+        for scope_id in scope_ids {
+            assert_eq!(ret.scopes.get_flags(scope_id).is_strict_mode(), is_strict);
+        }
+    }
+}
+
+#[cfg(not(miri))]
+#[test]
+fn static_prop_this_sourcemap() {
+    use oxc_sourcemap::SourcemapVisualizer;
+
+    // Temp vars which replace `this` retain span of `this`, so are mapped to it in source map
+    let source_text = "x = class {\n  static a = [this, this.b];\n  static c = () => this;\n};\n";
+
+    let source_type = SourceType::default();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    let options = TransformOptions::from(ESTarget::ES2021);
+    let ret = Transformer::new(&allocator, Path::new(""), &options).build_with_symbols_and_scopes(
+        symbols,
+        scopes,
+        &mut program,
+    );
+    assert!(ret.errors.is_empty());
+    let result = CodeGenerator::new()
+        .with_options(CodegenOptions {
+            single_quote: true,
+            source_map_path: Some(Path::new("test.js.map").to_path_buf()),
+            ..CodegenOptions::default()
+        })
+        .build(&program);
+
+    let output = result.code;
+    let output_map = result.map.unwrap();
+    let visualizer = SourcemapVisualizer::new(&output, &output_map);
+    let snapshot = visualizer.into_visualizer_text();
+    insta::assert_snapshot!("static_prop_this_sourcemap", snapshot);
+}
+
+#[test]
+fn static_prop_deeply_nested() {
+    let options = TransformOptions::from(ESTarget::ES2021);
+
+    // Realistic nesting depth is transformed as normal
+    let case = format!("class C {{ static x = {}this; }}", "1 + ".repeat(100));
+    assert!(test(&case, &options).is_ok());
+
+    // Excessive nesting depth raises an error, instead of overflowing the stack
+    for case in [
+        format!("class C {{ static x = {}this; }}", "1 + ".repeat(10_000)),
+        format!("class C {{ static x = 1; static {{ {}this; }} }}", "1 + ".repeat(10_000)),
+    ] {
+        let errors = test(&case, &options).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].to_string().contains("too deeply nested"));
+    }
+}
+
+#[test]
+fn static_prop_conditional_type_scope_flags() {
+    use oxc_ast::AstKind;
+
+    // Sloppy mode script
+    let source_text = "class C { static x = (y as (T extends string ? A<T> : B), this); }";
+    let source_type = SourceType::ts().with_script(true);
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    let semantic = SemanticBuilder::new().build(&program).semantic;
+    let scope_id = semantic
+        .nodes()
+        .iter()
+        .find_map(|node| match node.kind() {
+            AstKind::TSConditionalType(conditional) => conditional.scope_id.get(),
+            _ => None,
+        })
+        .unwrap();
+    let flags_before = semantic.scopes().get_flags(scope_id);
+    let (symbols, scopes) = semantic.into_symbol_table_and_scope_tree();
+    let options = TransformOptions::from(ESTarget::ES2021);
+    let ret = Transformer::new(&allocator, Path::new(""), &options).build_with_symbols_and_scopes(
+        symbols,
+        scopes,
+        &mut program,
+    );
+    assert!(ret.errors.is_empty());
+
+    // Conditional type was in class body, so is strict mode.
+    // Type is removed by TypeScript transform, so its scope is not moved out into sloppy mode code.
+    assert!(flags_before.contains(ScopeFlags::StrictMode));
+    assert_eq!(ret.scopes.get_flags(scope_id), flags_before);
+}
+
+#[cfg(not(miri))]
+#[test]
+fn static_prop_class_expression_sourcemap() {
+    use oxc_sourcemap::SourcemapVisualizer;
+
+    // Assignments to static properties have span of original property, so are mapped to it in source map
+    let source_text = "x = class C {\n  static a = 1;\n  static b = this.a;\n};\n";
+
+    let source_type = SourceType::default();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    let options = TransformOptions::from(ESTarget::ES2021);
+    let ret = Transformer::new(&allocator, Path::new(""), &options).build_with_symbols_and_scopes(
+        symbols,
+        scopes,
+        &mut program,
+    );
+    assert!(ret.errors.is_empty());
+    let result = CodeGenerator::new()
+        .with_options(CodegenOptions {
+            single_quote: true,
+            source_map_path: Some(Path::new("test.js.map").to_path_buf()),
+            ..CodegenOptions::default()
+        })
+        .build(&program);
+
+    let output = result.code;
+    let output_map = result.map.unwrap();
+    let visualizer = SourcemapVisualizer::new(&output, &output_map);
+    let snapshot = visualizer.into_visualizer_text();
+    insta::assert_snapshot!("static_prop_class_expression_sourcemap", snapshot);
+}
+
+#[test]
+fn static_prop_visitor_depths_balanced() {
+    // Exercises every visitor in `StaticVisitor` which alters `this_depth` or `scope_depth`.
+    // In debug builds, each visitor asserts the counters are restored on exit.
+    // TS type visitors are not exercised, because TypeScript transform removes types first.
+    // Nor is `visit_property_definition`, because nested class's properties have already been
+    // moved out of its class body by the time outer class's static initializers are transformed.
+    let body = "
+        this,
+        C,
+        function() { this; C; },
+        () => [this, C, function() { return () => this; }],
+        class {
+            static { this; C; }
+            [this.key] = this;
+            static [C.key] = () => this;
+            accessor [this.accessorKey] = this;
+            static accessor a = class { [this] = C; };
+            method() { return this; }
+            constructor() { this; new.target; C; () => this; }
+        },
+        this
+    ";
+    // Nested class is transformed before the static initializer containing it, so its
+    // static blocks are only visited if static blocks are not transformed
+    let mut options_without_static_blocks = TransformOptions::from(ESTarget::ES2021);
+    options_without_static_blocks.env.es2022.class_static_block = false;
+    for options in [TransformOptions::from(ESTarget::ES2021), options_without_static_blocks] {
+        for case in [
+            format!("class C {{ static x = ({body}); }}"),
+            format!("class C {{ static {{ ({body}); }} }}"),
+            format!("class C {{ static {{ let y = 1; ({body}); }} }}"),
+            format!("x = class C {{ static x = ({body}); }}; C = 1;"),
+        ] {
+            assert!(test(&case, &options).is_ok(), "{case}");
+        }
+    }
+}
+
+#[test]
+fn static_prop_temp_binding_in_symbols() {
+    // Temp vars for classes are returned in `TransformerReturn::class_temp_bindings`, keyed by
+    // class's `ScopeId`, and are in hoist scope outside class, so can be referenced by code
+    // generated after transform
+    let source_text = "x = class C { static y = this; }; class D { static z = D; } class E {}";
+    let source_type = SourceType::default();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    // Class's binding is declared by `Class` node, which is also the node of class's scope
+    let class_scope_id = |name: &str| {
+        let symbol_id = symbols.symbol_ids().find(|&id| symbols.get_name(id) == name).unwrap();
+        let node_id = symbols.get_declaration(symbol_id);
+        scopes
+            .descendants_from_root()
+            .find(|&scope_id| scopes.get_node_id(scope_id) == node_id)
+            .unwrap()
+    };
+    let (c_scope_id, d_scope_id, e_scope_id) =
+        (class_scope_id("C"), class_scope_id("D"), class_scope_id("E"));
+
+    let options = TransformOptions::from(ESTarget::ES2021);
+    let ret = Transformer::new(&allocator, Path::new(""), &options).build_with_symbols_and_scopes(
+        symbols,
+        scopes,
+        &mut program,
+    );
+    assert!(ret.errors.is_empty());
+    // `E` is not transformed, so has no temp var
+    assert_eq!(ret.class_temp_bindings.len(), 2);
+    assert!(!ret.class_temp_bindings.contains_key(&e_scope_id));
+
+    let root_scope_id = ret.scopes.root_scope_id();
+    let symbol_id = ret.class_temp_bindings[&c_scope_id];
+    assert_eq!(ret.symbols.get_name(symbol_id), "_C");
+    assert_eq!(ret.symbols.get_scope_id(symbol_id), root_scope_id);
+    // `(_C = class C {}, _defineProperty(_C, "y", _C), _C)`
+    assert_eq!(ret.symbols.get_resolved_reference_ids(symbol_id).len(), 4);
+
+    let symbol_id = ret.class_temp_bindings[&d_scope_id];
+    assert_eq!(ret.symbols.get_name(symbol_id), "_D");
+    assert_eq!(ret.symbols.get_scope_id(symbol_id), root_scope_id);
+    // `_D = D; _defineProperty(D, "z", _D);`
+    assert_eq!(ret.symbols.get_resolved_reference_ids(symbol_id).len(), 2);
+}
+
+#[test]
+fn static_prop_class_name_reference_ids_retained() {
+    // References to class name in static initializers which are renamed to temp var keep their
+    // `ReferenceId`s, so later passes can find them in temp var's resolved references
+    let source_text =
+        "class C { static x = [C, this, C.y]; static { f(C); } m() { return C; } }\nC;";
+    let source_type = SourceType::default();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    let class_symbol_id = scopes.get_binding(scopes.root_scope_id(), "C").unwrap();
+    let reference_ids_before =
+        symbols.get_resolved_reference_ids(class_symbol_id).iter().copied().collect::<Vec<_>>();
+    assert_eq!(reference_ids_before.len(), 5);
+
+    let options = TransformOptions::from(ESTarget::ES2021);
+    let ret = Transformer::new(&allocator, Path::new(""), &options).build_with_symbols_and_scopes(
+        symbols,
+        scopes,
+        &mut program,
+    );
+    assert!(ret.errors.is_empty());
+
+    let root_scope_id = ret.scopes.root_scope_id();
+    let temp_symbol_id = ret.scopes.get_binding(root_scope_id, "_C").unwrap();
+    let (renamed, not_renamed): (Vec<_>, Vec<_>) =
+        reference_ids_before.iter().partition(|&&reference_id| {
+            ret.symbols.get_reference(reference_id).symbol_id() == Some(temp_symbol_id)
+        });
+    // `C`, `C.y`, `f(C)`
+    assert_eq!(renamed.len(), 3);
+    assert!(
+        renamed
+            .iter()
+            .all(|id| ret.symbols.get_resolved_reference_ids(temp_symbol_id).contains(id))
+    );
+    // `return C`, `C;`
+    assert_eq!(not_renamed.len(), 2);
+    assert!(
+        not_renamed
+            .iter()
+            .all(|id| ret.symbols.get_resolved_reference_ids(class_symbol_id).contains(id))
+    );
+}
+
+#[test]
+fn static_prop_class_name_references_moved() {
+    // References to class name in static initializers are moved to temp var in bulk after traversal.
+    // Symbols' resolved references must match the symbol each reference resolves to.
+    let references = "C, ".repeat(100);
+    let source_text = format!(
+        "class C {{ static x = [{references}]; static {{ [{references}]; }} static y = () => C; }} C = 1;"
+    );
+    let source_type = SourceType::default();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, &source_text, source_type).parse();
+    let mut program = ret.program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    let options = TransformOptions::from(ESTarget::ES2021);
+    let ret = Transformer::new(&allocator, Path::new(""), &options).build_with_symbols_and_scopes(
+        symbols,
+        scopes,
+        &mut program,
+    );
+    assert!(ret.errors.is_empty());
+
+    let root_scope_id = ret.scopes.root_scope_id();
+    let class_symbol_id = ret.scopes.get_binding(root_scope_id, "C").unwrap();
+    let temp_symbol_id = ret.scopes.get_binding(root_scope_id, "_C").unwrap();
+
+    for symbol_id in [class_symbol_id, temp_symbol_id] {
+        let reference_ids = ret.symbols.get_resolved_reference_ids(symbol_id);
+        for &reference_id in reference_ids {
+            assert_eq!(ret.symbols.get_reference(reference_id).symbol_id(), Some(symbol_id));
+        }
+        let resolving_count = ret
+            .symbols
+            .references
+            .iter()
+            .filter(|reference| reference.symbol_id() == Some(symbol_id))
+            .count();
+        assert_eq!(reference_ids.len(), resolving_count);
+    }
+
+    // 201 renamed references, and `_C = C`
+    assert_eq!(ret.symbols.get_resolved_reference_ids(temp_symbol_id).len(), 202);
+}
+
+#[test]
+fn static_prop_comments_hoisted() {
+    // Comments attached to static property are attached to `C.x = 1` statement after transform,
+    // so they are printed around it.
+    let source_text =
+        "class C {\n  /** leading */\n  static x = 1; // trailing\n}\nfoo(); // after\n";
+    let source_type = SourceType::default();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    let mut options = TransformOptions::from(ESTarget::ES2021);
+    options.assumptions.set_public_class_fields = true;
+    let ret = Transformer::new(&allocator, Path::new(""), &options).build_with_symbols_and_scopes(
+        symbols,
+        scopes,
+        &mut program,
+    );
+    assert!(ret.errors.is_empty());
+
+    let Statement::ExpressionStatement(stmt) = &program.body[1] else { unreachable!() };
+    assert!(stmt.expression.is_assignment());
+    let comment_text = |attached_to: u32| {
+        program
+            .comments
+            .iter()
+            .filter(|comment| comment.attached_to == attached_to)
+            .map(|comment| comment.span.source_text(source_text))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(comment_text(stmt.span.start), ["/** leading */"]);
+    assert_eq!(comment_text(stmt.span.end), ["// trailing"]);
+
+    let code = CodeGenerator::new().build(&program).code;
+    assert_eq!(code, "class C {}\n/** leading */\nC.x = 1; // trailing\nfoo(); // after\n");
+}
+
+#[test]
+fn static_prop_delete_this_comments() {
+    // `delete this` is replaced with `true` with same span, so comments attached to `delete this`
+    // remain attached to `true`
+    let source_text = "class C {\n  static x = /* leading */ delete this;\n}\n";
+    let source_type = SourceType::default();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    let mut options = TransformOptions::from(ESTarget::ES2021);
+    options.assumptions.set_public_class_fields = true;
+    let ret = Transformer::new(&allocator, Path::new(""), &options).build_with_symbols_and_scopes(
+        symbols,
+        scopes,
+        &mut program,
+    );
+    assert!(ret.errors.is_empty());
+
+    let Statement::ExpressionStatement(stmt) = &program.body[1] else { unreachable!() };
+    let Expression::AssignmentExpression(assign) = &stmt.expression else { unreachable!() };
+    let Expression::BooleanLiteral(lit) = &assign.right else { unreachable!() };
+    assert_eq!(lit.span.source_text(source_text), "delete this");
+    let comment_text = |attached_to: u32| {
+        program
+            .comments
+            .iter()
+            .filter(|comment| comment.attached_to == attached_to)
+            .map(|comment| comment.span.source_text(source_text))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(comment_text(lit.span.start), ["/* leading */"]);
+
+    // Codegen does not print plain block comments before an assignment's right side,
+    // same as for `delete this` itself
+    let code = CodeGenerator::new().build(&program).code;
+    assert_eq!(code, "class C {}\nC.x = true;\n");
+}
+
+#[test]
+fn static_prop_transform_idempotent() {
+    // Transforming output of transform again does not alter it. Static properties and static blocks
+    // are all removed from classes in 1st pass, so there's nothing left for 2nd pass to rewrite -
+    // temp vars for class are ordinary variables by then.
+    let babel_options: BabelOptions = serde_json::from_str(
+        r#"{
+            "plugins": [
+                "transform-class-static-block",
+                ["transform-class-properties", { "strictModeIifeForStaticInitializers": true }]
+            ]
+        }"#,
+    )
+    .unwrap();
+    let strict_mode_iife_options = TransformOptions::try_from(&babel_options).unwrap();
+
+    let cases = [
+        "class C { static x = this; static y = C; static z = () => [this, C]; }",
+        "x = class C { static x = this; static y = C; static { this.z = C; } }",
+        "x = class { static x = this; static { this.y = 1; } }",
+        "class C extends S { static x = super.y; static { super.z(); } }",
+        "class C { static #x = 1; static y = C.#x; static #m() { return this; } static z = C.#m(); }",
+        "class C { static x = class D { static y = [this, C, D]; }; }",
+        "class C { static x = foo; } C = 1;",
+        "function f() { class C { static x = function() { return this; }; } }",
+    ];
+    for options in [&TransformOptions::from(ESTarget::ES2021), &strict_mode_iife_options] {
+        for case in cases {
+            let first = test(case, options).unwrap();
+            assert!(!first.contains("static"), "{case}");
+            let second = test(&first, options).unwrap();
+            assert_eq!(first, second, "{case}");
+        }
+    }
+}
+
+#[test]
+fn static_prop_inline_class_temp_var_symbol() {
+    // Temp var which is inlined is never created
+    let babel_options: BabelOptions = serde_json::from_value(serde_json::json!({
+        "plugins": [["transform-class-properties", { "inlineClassTempVar": true }]]
+    }))
+    .unwrap();
+    let options = TransformOptions::try_from(&babel_options).unwrap();
+
+    let source_text = "x = class { static a = 1; static b = 2; };";
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    let ret = Transformer::new(&allocator, Path::new(""), &options).build_with_symbols_and_scopes(
+        symbols,
+        scopes,
+        &mut program,
+    );
+    assert!(ret.errors.is_empty());
+    assert!(ret.class_temp_bindings.is_empty());
+    assert!(ret.scopes.get_root_binding("_Class").is_none());
+
+    assert!(ret.symbols.names().all(|name| name != "_Class"));
+}
+
+/// Visitor for `static_prop_deeply_nested_classes` test.
+struct CheckReferences<'s> {
+    symbols: &'s oxc_semantic::SymbolTable,
+    temp_reference_counts: Vec<usize>,
+    class_name_references: Vec<String>,
+}
+impl<'a> Visit<'a> for CheckReferences<'_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let reference = self.symbols.get_reference(ident.reference_id());
+        let Some(symbol_id) = reference.symbol_id() else { return };
+        assert_eq!(self.symbols.get_name(symbol_id), ident.name.as_str());
+        if let Some(letter) = ident.name.strip_prefix("_C") {
+            let i = usize::from(letter.as_bytes()[0] - b'a');
+            self.temp_reference_counts[i] += 1;
+        } else if ident.name.starts_with('C') {
+            self.class_name_references.push(ident.name.to_string());
+        }
+    }
+}
+
+#[test]
+fn static_prop_deeply_nested_classes() {
+    // Each class references its own name and `this`, and names of all classes it's nested in.
+    // `class Ca { static s = this; static n = Ca; static o = []; static x = class Cb { ... static o = [Ca]; }; }`
+    // Class names don't end with digits, as `generate_uid` strips them (`A1` -> `_A`).
+    const DEPTH: usize = 20;
+    let name = |i: usize| format!("C{}", char::from(b'a' + u8::try_from(i).unwrap()));
+    let mut source_text = String::new();
+    for i in 0..DEPTH {
+        let outer_names = (0..i).map(name).collect::<Vec<_>>().join(", ");
+        let keyword = if i == 0 { "class" } else { "static x = class" };
+        let name = name(i);
+        source_text.push_str(&format!(
+            "{keyword} {name} {{ static s = this; static n = {name}; static o = [{outer_names}];\n"
+        ));
+    }
+    for i in (0..DEPTH).rev() {
+        source_text.push_str(if i == 0 { "}\n" } else { "};\n" });
+    }
+
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, &source_text, SourceType::mjs()).parse();
+    let mut program = ret.program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    let options = TransformOptions::from(ESTarget::ES2021);
+    let ret = Transformer::new(&allocator, Path::new(""), &options).build_with_symbols_and_scopes(
+        symbols,
+        scopes,
+        &mut program,
+    );
+    assert!(ret.errors.is_empty());
+
+    // Each class has its own temp var, with a distinct symbol
+    let root_scope_id = ret.scopes.root_scope_id();
+    let temp_symbol_ids = (0..DEPTH)
+        .map(|i| ret.scopes.get_binding(root_scope_id, &format!("_{}", name(i))).unwrap())
+        .collect::<Vec<_>>();
+    for (i, &symbol_id) in temp_symbol_ids.iter().enumerate() {
+        assert!(!temp_symbol_ids[..i].contains(&symbol_id));
+    }
+
+    // Every reference is bound to symbol with same name as the reference.
+    // No references to class names remain in static initializers. Each temp var is referenced by
+    // its own class, and once by each class nested within it.
+    let mut visitor = CheckReferences {
+        symbols: &ret.symbols,
+        temp_reference_counts: vec![0; DEPTH],
+        class_name_references: vec![],
+    };
+    visitor.visit_program(&program);
+
+    // Only references to a class name are `Ca` after class declaration:
+    // `_Ca = Ca` and as assignee of its 4 static properties `_defineProperty(Ca, "s", _Ca)`
+    assert_eq!(visitor.class_name_references, ["Ca"; 5]);
+
+    for (i, &count) in visitor.temp_reference_counts.iter().enumerate() {
+        let own = match i {
+            // `_Ca = Ca`, `this`, `Ca`
+            0 => 3,
+            // `_Cb = class Cb {}`, `this`, `Cb`, trailing `_Cb`, and assignee of 4 static props
+            // (3 for innermost class, which has no `x` prop)
+            i if i == DEPTH - 1 => 7,
+            _ => 8,
+        };
+        assert_eq!(count, own + (DEPTH - 1 - i), "references to `_{}`", name(i));
+        assert_eq!(ret.symbols.get_resolved_reference_ids(temp_symbol_ids[i]).len(), count);
+    }
+}
+
+#[test]
+fn static_prop_ts_expression_wrappers() {
+    // TypeScript transform usually removes `as`, `satisfies`, `!` and `<T>` wrappers before
+    // static initializers are transformed. Check `this`, class name and `super` are still transformed
+    // inside them, if they're not removed (TypeScript transform not run).
+    // Type `C` in `<C>` refers to the class's type, so is not replaced with temp var.
+    let source_text = "class C extends S {
+  static a = this.y as number;
+  static b = C satisfies object;
+  static c = this!.y;
+  static d = (super.z as any)!;
+  static e = <C>(<unknown>this);
+}";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::ts()).parse();
+    let mut program = ret.program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    // Skip TypeScript transform
+    program.source_type = SourceType::mjs();
+    let options = TransformOptions::from(ESTarget::ES2021);
+    let ret = Transformer::new(&allocator, Path::new(""), &options).build_with_symbols_and_scopes(
+        symbols,
+        scopes,
+        &mut program,
+    );
+    assert!(ret.errors.is_empty());
+
+    let code = CodeGenerator::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code;
+    let expected = "import _defineProperty from '@oxc-project/runtime/helpers/defineProperty';
+import _superPropGet from '@oxc-project/runtime/helpers/superPropGet';
+var _C;
+class C extends S {}
+_C = C;
+_defineProperty(C, 'a', _C.y as number);
+_defineProperty(C, 'b', ((_C) satisfies object));
+_defineProperty(C, 'c', _C!.y);
+_defineProperty(C, 'd', (_superPropGet(_C, 'z', _C) as any)!);
+_defineProperty(C, 'e', (<C>(<unknown>_C)));
+";
+    assert_eq!(code, expected);
+}
+
+/// Get result of [`Transformer::class_properties_will_walk_deep`] for first class in `source_text`.
+pub fn will_walk_deep(
+    source_text: &str,
+    source_type: SourceType,
+    options: &TransformOptions,
+) -> bool {
+    struct FirstClass<'a, 'b> {
+        transformer: &'b Transformer<'a>,
+        scopes: &'b ScopeTree,
+        will_walk_deep: Option<bool>,
+    }
+
+    impl<'a> Visit<'a> for FirstClass<'a, '_> {
+        fn visit_class(&mut self, class: &Class<'a>) {
+            if self.will_walk_deep.is_none() {
+                self.will_walk_deep =
+                    Some(self.transformer.class_properties_will_walk_deep(class, self.scopes));
+            }
+        }
+    }
+
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let (_, scopes) =
+        SemanticBuilder::new().build(&ret.program).semantic.into_symbol_table_and_scope_tree();
+    let transformer = Transformer::new(&allocator, Path::new(""), options);
+    let mut first_class =
+        FirstClass { transformer: &transformer, scopes: &scopes, will_walk_deep: None };
+    first_class.visit_program(&ret.program);
+    first_class.will_walk_deep.expect("no class in source")
+}
+
+#[test]
+fn static_prop_will_walk_deep() {
+    let options = TransformOptions::from(ESTarget::ES2021);
+    let babel_options: BabelOptions = serde_json::from_value(serde_json::json!({
+        "plugins": [["transform-class-properties", { "strictModeIifeForStaticInitializers": true }]]
+    }))
+    .unwrap();
+    let strict_mode_iife_options = TransformOptions::try_from(&babel_options).unwrap();
+
+    let named = "class C { static x = () => this; }";
+    let anonymous = "x = class { static x = () => this; };";
+    let anonymous_block = "x = class { static { this.y = () => this; } };";
+    let anonymous_export_default = "export default class { static x = () => this; }";
+
+    // Named class: deep, whether strict mode or sloppy mode
+    assert!(will_walk_deep(named, SourceType::mjs(), &options));
+    assert!(will_walk_deep(named, SourceType::cjs(), &options));
+    // Anonymous class: deep only if scopes need converting to sloppy mode
+    assert!(!will_walk_deep(anonymous, SourceType::mjs(), &options));
+    assert!(will_walk_deep(anonymous, SourceType::cjs(), &options));
+    // Anonymous `export default class {}` with static properties is given a name
+    assert!(will_walk_deep(anonymous_export_default, SourceType::mjs(), &options));
+    // Strict mode IIFE avoids converting static property initializers to sloppy mode, but not static blocks
+    assert!(!will_walk_deep(anonymous, SourceType::cjs(), &strict_mode_iife_options));
+    assert!(will_walk_deep(anonymous_block, SourceType::cjs(), &options));
+
+    // Class properties transform disabled
+    let options = TransformOptions::from(ESTarget::ES2022);
+    assert!(!will_walk_deep(named, SourceType::mjs(), &options));
+}
+
+#[test]
+fn static_prop_adopt_class_temp_bindings() {
+    // `var _C` / `var _Class` stand in for temp vars created by a previous pass
+    fn transform(source_text: &str, adopt: bool) -> String {
+        struct ClassScopes(Vec<ScopeId>);
+
+        impl<'a> Visit<'a> for ClassScopes {
+            fn visit_class(&mut self, class: &Class<'a>) {
+                self.0.push(class.scope_id());
+            }
+        }
+
+        let allocator = Allocator::default();
+        let source_type = SourceType::mjs();
+        let mut program = Parser::new(&allocator, source_text, source_type).parse().program;
+        let (symbols, scopes) =
+            SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+
+        let mut class_scopes = ClassScopes(vec![]);
+        class_scopes.visit_program(&program);
+        let temp_bindings = ["_C", "_Class"]
+            .into_iter()
+            .map(|name| scopes.get_root_binding(name).unwrap())
+            .zip(class_scopes.0)
+            .map(|(symbol_id, scope_id)| (scope_id, symbol_id))
+            .collect::<FxHashMap<_, _>>();
+
+        let options = TransformOptions::from(ESTarget::ES2021);
+        let mut transformer = Transformer::new(&allocator, Path::new(""), &options);
+        if adopt {
+            transformer = transformer.with_class_temp_bindings(temp_bindings);
+        }
+        let ret = transformer.build_with_symbols_and_scopes(symbols, scopes, &mut program);
+        assert!(ret.errors.is_empty());
+        CodeGenerator::new()
+            .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+            .build(&program)
+            .code
+    }
+
+    let source_text = "
+        var _C, _Class;
+        class C { static x = this; }
+        x = class { static y = 1; };
+    ";
+
+    let expected = "import _defineProperty from '@oxc-project/runtime/helpers/defineProperty';
+var _C, _Class;
+class C {}
+_C = C;
+_defineProperty(C, 'x', _C);
+x = (_Class = class {}, _defineProperty(_Class, 'y', 1), _Class);
+";
+    assert_eq!(transform(source_text, true), expected);
+
+    // Without adopting temp vars, new ones are created
+    let output = transform(source_text, false);
+    assert!(output.contains("var _C2, _Class2;"));
+}
+
+#[test]
+fn static_prop_provided_strictness() {
+    use oxc_ast::AstKind;
+
+    // Strictness provided by caller is used for static property initializers instead of scope flags.
+    // Static blocks still use scope flags.
+    let source_text = "x = class { static x = () => this; static { let f = function() {}; f(); } }";
+    for (source_type, is_strict) in [(SourceType::mjs(), false), (SourceType::cjs(), true)] {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let mut program = ret.program;
+        let semantic = SemanticBuilder::new().build(&program).semantic;
+        let (mut class_scope_id, mut arrow_scope_id, mut func_scope_id) = (None, None, None);
+        for node in semantic.nodes().iter() {
+            match node.kind() {
+                AstKind::Class(class) => class_scope_id = class.scope_id.get(),
+                AstKind::ArrowFunctionExpression(arrow) => arrow_scope_id = arrow.scope_id.get(),
+                AstKind::Function(func) => func_scope_id = func.scope_id.get(),
+                _ => {}
+            }
+        }
+        let class_scope_id = class_scope_id.unwrap();
+        let (symbols, scopes) = semantic.into_symbol_table_and_scope_tree();
+
+        let options = TransformOptions::from(ESTarget::ES2021);
+        let strictness = FxHashMap::from_iter([(class_scope_id, is_strict)]);
+        let transformer = Transformer::new(&allocator, Path::new(""), &options)
+            .with_static_initializer_strictness(strictness);
+
+        // Either static property initializer (module) or static block (script) is converted
+        // to sloppy mode, so walk is deep
+        let Statement::ExpressionStatement(stmt) = &program.body[0] else { unreachable!() };
+        let Expression::AssignmentExpression(assign) = &stmt.expression else { unreachable!() };
+        let Expression::ClassExpression(class) = &assign.right else { unreachable!() };
+        assert!(transformer.class_properties_will_walk_deep(class, &scopes));
+
+        let ret = transformer.build_with_symbols_and_scopes(symbols, scopes, &mut program);
+        assert!(ret.errors.is_empty());
+        assert_eq!(ret.scopes.get_flags(arrow_scope_id.unwrap()).is_strict_mode(), is_strict);
+        assert_eq!(ret.scopes.get_flags(func_scope_id.unwrap()).is_strict_mode(), !is_strict);
+    }
+
+    // Anonymous class with only static properties in sloppy mode script doesn't need walking deep
+    // if code outside it is provided as strict mode
+    let source_text = "x = class { static x = () => this; };";
+    let allocator = Allocator::default();
+    let program = Parser::new(&allocator, source_text, SourceType::cjs()).parse().program;
+    let (_, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    let Statement::ExpressionStatement(stmt) = &program.body[0] else { unreachable!() };
+    let Expression::AssignmentExpression(assign) = &stmt.expression else { unreachable!() };
+    let Expression::ClassExpression(class) = &assign.right else { unreachable!() };
+    let options = TransformOptions::from(ESTarget::ES2021);
+    let transformer = Transformer::new(&allocator, Path::new(""), &options);
+    assert!(transformer.class_properties_will_walk_deep(class, &scopes));
+    let transformer = transformer
+        .with_static_initializer_strictness(FxHashMap::from_iter([(class.scope_id(), true)]));
+    assert!(!transformer.class_properties_will_walk_deep(class, &scopes));
+}
+
+#[test]
+fn static_prop_static_this_replacement() {
+    use oxc_span::Atom;
+    use oxc_syntax::reference::ReferenceFlags;
+
+    let source_text = "
+        class C {
+            static x = this;
+            static y = this.x;
+            static z = C.x;
+            static fn = () => this;
+            static method = function() { return this; };
+            static { this.init(); }
+        }
+        function outer(mixinTarget) {
+            return class D { static self = this; };
+        }
+        x = class E { static self = this; };
+    ";
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    let e_symbol_id = symbols.symbol_ids().find(|&id| symbols.get_name(id) == "E").unwrap();
+    let e_scope_id = symbols.get_scope_id(e_symbol_id);
+
+    // Replace `this` with `mixinTarget`, except in class `E`
+    let options = TransformOptions::from(ESTarget::ES2021);
+    let ret = Transformer::new(&allocator, Path::new(""), &options)
+        .with_static_this_replacement(Box::new(move |class_scope_id, scope_id, span, ctx| {
+            if class_scope_id == e_scope_id {
+                return None;
+            }
+            let name = Atom::from("mixinTarget");
+            let symbol_id = ctx.scopes().find_binding(scope_id, &name);
+            Some(ctx.create_ident_expr(span, name, symbol_id, ReferenceFlags::Read))
+        }))
+        .build_with_symbols_and_scopes(symbols, scopes, &mut program);
+    assert!(ret.errors.is_empty());
+
+    let output = CodeGenerator::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code;
+    let expected = "
+        import _defineProperty from '@oxc-project/runtime/helpers/defineProperty';
+        var _C, _E;
+        class C {}
+        _C = C;
+        _defineProperty(C, 'x', mixinTarget);
+        _defineProperty(C, 'y', mixinTarget.x);
+        _defineProperty(C, 'z', _C.x);
+        _defineProperty(C, 'fn', () => mixinTarget);
+        _defineProperty(C, 'method', function() {
+            return this;
+        });
+        mixinTarget.init();
+        function outer(mixinTarget) {
+            var _D;
+            return _D = class D {}, _defineProperty(_D, 'self', mixinTarget), _D;
+        }
+        x = (_E = class E {}, _defineProperty(_E, 'self', _E), _E);
+    ";
+    assert_eq!(output, codegen(expected, SourceType::mjs()));
+
+    // `mixinTarget` in `outer` resolves to parameter, elsewhere it's unresolved
+    let root_scope_id = ret.scopes.root_scope_id();
+    assert_eq!(ret.scopes.root_unresolved_references().get("mixinTarget").unwrap().len(), 4);
+    let outer_symbol_id = ret
+        .symbols
+        .symbol_ids()
+        .find(|&id| {
+            ret.symbols.get_name(id) == "mixinTarget"
+                && ret.symbols.get_scope_id(id) != root_scope_id
+        })
+        .unwrap();
+    assert_eq!(ret.symbols.get_resolved_reference_ids(outer_symbol_id).len(), 1);
+}
@@ -0,0 +1,81 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{ClassPropertiesStats, TransformOptions, Transformer};
+
+use crate::{class_properties::will_walk_deep, test};
+
+#[test]
+fn shallow_and_deep_traversal() {
+    let options = TransformOptions::from_target("es2021").unwrap();
+
+    ClassPropertiesStats::reset();
+    let source = "
+        // Anonymous class: shallow
+        x = class { static a = this; static b = () => this; };
+        // Named class: deep
+        class C { static a = this; }
+        // Anonymous class, static block with single expression: shallow
+        y = class { static { this.x(); } };
+        // Named class, static block with multiple statements: deep
+        class D { static { this.x(); this.y(); } }
+    ";
+    test(source, &options).unwrap();
+
+    assert_eq!(ClassPropertiesStats::get(), ClassPropertiesStats { shallow: 3, deep: 2 });
+}
+
+#[test]
+fn no_traversal_without_static_initializers() {
+    let options = TransformOptions::from_target("es2021").unwrap();
+
+    ClassPropertiesStats::reset();
+    let source = "
+        // Only methods: class is not transformed
+        class C { static a() { return this; } b() { return C; } static get c() { return this; } }
+        // Instance properties and private methods: transformed, but nothing is moved out of class
+        class D { x = this; #y() { return this; } static z() { return D; } }
+    ";
+    test(source, &options).unwrap();
+
+    assert_eq!(ClassPropertiesStats::get(), ClassPropertiesStats { shallow: 0, deep: 0 });
+}
+
+#[test]
+fn will_walk_deep_matches_traversal() {
+    let options = TransformOptions::from_target("es2021").unwrap();
+
+    let cases = [
+        "class C { static a = () => this; }",
+        "x = class { static a = () => this; };",
+        "x = class C { static a = () => this; };",
+        "x = class { static { this.a = () => this; } };",
+        "export default class { static a = () => this; }",
+    ];
+    for case in cases {
+        for source_type in [SourceType::mjs(), SourceType::cjs()] {
+            if source_type.is_script() && case.starts_with("export") {
+                continue;
+            }
+
+            ClassPropertiesStats::reset();
+            let allocator = Allocator::default();
+            let mut program = Parser::new(&allocator, case, source_type).parse().program;
+            let (symbols, scopes) =
+                SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+            let ret = Transformer::new(&allocator, Path::new(""), &options)
+                .build_with_symbols_and_scopes(symbols, scopes, &mut program);
+            assert!(ret.errors.is_empty());
+
+            let expected = if will_walk_deep(case, source_type, &options) {
+                ClassPropertiesStats { shallow: 0, deep: 1 }
+            } else {
+                ClassPropertiesStats { shallow: 1, deep: 0 }
+            };
+            assert_eq!(ClassPropertiesStats::get(), expected, "{case} {source_type:?}");
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use std::{
+    io,
+    sync::{Arc, Mutex},
+};
+
+use oxc_transformer::TransformOptions;
+
+use crate::test;
+
+/// Writer which collects log output into a shared buffer.
+#[derive(Clone, Default)]
+struct LogBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl io::Write for LogBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn reparent_scope_logged() {
+    let buffer = LogBuffer::default();
+    let writer = buffer.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::TRACE)
+        .with_writer(move || writer.clone())
+        .with_ansi(false)
+        .without_time()
+        .with_target(false)
+        .finish();
+
+    let options = TransformOptions::from_target("es2021").unwrap();
+    // Scopes: 0 = program, 1 = function `f`, 2 = class `C`, 3 = arrow function, 4 = function expression.
+    // Scopes 3 and 4 are moved from class scope to function `f` scope.
+    let source = "function f() { class C { static x = () => this; static y = function() {}; } }";
+    tracing::subscriber::with_default(subscriber, || test(source, &options).unwrap());
+
+    let log = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+    let reparents = log
+        .lines()
+        .filter_map(|line| line.split_once("reparent scope ").map(|(_, fields)| fields))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        reparents,
+        [
+            "scope_id=ScopeId(3) flags=ScopeFlags(StrictMode | Function | Arrow) node_id=NodeId(11) old_parent_id=Some(ScopeId(2)) new_parent_id=ScopeId(1)",
+            "scope_id=ScopeId(4) flags=ScopeFlags(StrictMode | Function) node_id=NodeId(19) old_parent_id=Some(ScopeId(2)) new_parent_id=ScopeId(1)",
+        ]
+    );
+}
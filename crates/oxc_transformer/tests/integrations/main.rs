@@ -1,3 +1,8 @@
+mod class_properties;
+#[cfg(feature = "transform_stats")]
+mod class_properties_stats;
+#[cfg(feature = "tracing")]
+mod class_properties_tracing;
 mod es_target;
 mod plugins;
 mod targets;
@@ -35,3 +35,27 @@ fn targets() {
         assert_eq!(test(case, &options), test(case, &options_node));
     }
 }
+
+#[test]
+fn targets_class_features() {
+    let case = "class C { #x = 1; static y = this.#x; static { z(this); } }";
+
+    // Class properties and static blocks are transformed only if unsupported by targets.
+    // (query, class properties supported, static blocks supported)
+    let cases = [
+        ("chrome 93", false, false),
+        ("chrome 95", false, true),
+        ("safari 16", true, false),
+        ("chrome 98", true, true),
+    ];
+
+    for (query, class_properties_supported, static_blocks_supported) in cases {
+        let options = TransformOptions {
+            env: EnvOptions::from_browserslist_query(query).unwrap(),
+            ..TransformOptions::default()
+        };
+        let code = test(case, &options).unwrap();
+        assert_eq!(code.contains("#x"), class_properties_supported, "{query}\n{code}");
+        assert_eq!(code.contains("static {"), static_blocks_supported, "{query}\n{code}");
+    }
+}
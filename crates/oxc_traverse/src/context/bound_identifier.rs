@@ -94,7 +94,14 @@ impl<'a> BoundIdentifier<'a> {
         self.create_spanned_reference(span, ReferenceFlags::Read, ctx)
     }
 
-    /// Create `Expression::Identifier` referencing this binding, which is read from, with specified `Span`
+    /// Create `Expression::Identifier` referencing this binding, which is read from, with specified `Span`.
+    ///
+    /// `span` is used only for the `IdentifierReference`. It does not affect the binding.
+    /// Pass the span of the node being replaced (e.g. `this`) so source maps point to the original
+    /// code, or `SPAN` for a purely synthetic read.
+    ///
+    /// A new `ReferenceId` is created and registered as a resolved reference of the binding's symbol,
+    /// so later passes see the read.
     pub fn create_spanned_read_expression(
         &self,
         span: Span,
@@ -319,3 +326,66 @@ impl<'a> BoundIdentifier<'a> {
         SimpleAssignmentTarget::AssignmentTargetIdentifier(ctx.alloc(ident))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::{Expression, Program};
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::{SourceType, Span};
+    use oxc_syntax::{
+        reference::{ReferenceFlags, ReferenceId},
+        symbol::{SymbolFlags, SymbolId},
+    };
+
+    use crate::{Traverse, TraverseCtx, traverse_mut};
+
+    #[derive(Default)]
+    struct CreateReads {
+        symbol_id: Option<SymbolId>,
+        reads: Vec<ReferenceId>,
+    }
+
+    impl<'a> Traverse<'a> for CreateReads {
+        fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+            let binding =
+                ctx.generate_uid_in_root_scope("this", SymbolFlags::FunctionScopedVariable);
+            self.symbol_id = Some(binding.symbol_id);
+
+            for span in [Span::new(0, 4), Span::new(6, 10)] {
+                let expr = binding.create_spanned_read_expression(span, ctx);
+                let Expression::Identifier(ident) = &expr else { unreachable!() };
+                assert_eq!(ident.span, span);
+                assert_eq!(ident.name, binding.name);
+                self.reads.push(ident.reference_id());
+                program.body.push(ctx.ast.statement_expression(span, expr));
+            }
+        }
+    }
+
+    #[test]
+    fn create_spanned_read_expression() {
+        let source = "this; this;";
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, SourceType::mjs()).parse();
+        let mut program = ret.program;
+        let (symbols, scopes) =
+            SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+
+        let mut traverser = CreateReads::default();
+        let (symbols, _scopes) =
+            traverse_mut(&mut traverser, &allocator, &mut program, symbols, scopes);
+
+        let symbol_id = traverser.symbol_id.unwrap();
+        let reference_ids = symbols.get_resolved_reference_ids(symbol_id);
+        assert_eq!(reference_ids.len(), 2);
+        for reference_id in &traverser.reads {
+            assert!(reference_ids.contains(reference_id));
+            let reference = symbols.get_reference(*reference_id);
+            assert_eq!(reference.symbol_id(), Some(symbol_id));
+            assert_eq!(reference.flags(), ReferenceFlags::Read);
+        }
+        assert_ne!(traverser.reads[0], traverser.reads[1]);
+    }
+}
@@ -1,7 +1,7 @@
 use oxc_allocator::{Allocator, Box};
 use oxc_ast::{
     AstBuilder,
-    ast::{Expression, IdentifierReference, Statement},
+    ast::{Expression, IdentifierReference, Program, Statement},
 };
 use oxc_semantic::{ScopeTree, SymbolTable};
 use oxc_span::{Atom, CompactStr, Span};
@@ -236,6 +236,22 @@ impl<'a> TraverseCtx<'a> {
         self.scoping.symbols_mut()
     }
 
+    /// Check if a symbol is exported from the module.
+    ///
+    /// Shortcut for `ctx.scoping.symbol_is_exported`.
+    #[inline]
+    pub fn symbol_is_exported(&self, symbol_id: SymbolId) -> bool {
+        self.scoping.symbol_is_exported(symbol_id)
+    }
+
+    /// Collect symbols which are exported from the module, for use by [`Self::symbol_is_exported`].
+    ///
+    /// Shortcut for `ctx.scoping.collect_exported_symbols`.
+    #[inline]
+    pub fn collect_exported_symbols(&mut self, program: &Program<'a>) {
+        self.scoping.collect_exported_symbols(program);
+    }
+
     /// Get iterator over scopes, starting with current scope and working up.
     ///
     /// This is a shortcut for `ctx.scoping.parent_scopes`.
@@ -594,6 +610,34 @@ impl<'a> TraverseCtx<'a> {
         self.scoping.delete_reference_for_identifier(ident);
     }
 
+    /// Rebind a reference from one symbol to another.
+    ///
+    /// Reference must currently be bound to `old_symbol_id`. Panics in debug mode if not.
+    ///
+    /// This is a shortcut for `ctx.scoping.rebind_reference`.
+    pub fn rebind_reference(
+        &mut self,
+        reference_id: ReferenceId,
+        old_symbol_id: SymbolId,
+        new_symbol_id: SymbolId,
+    ) {
+        self.scoping.rebind_reference(reference_id, old_symbol_id, new_symbol_id);
+    }
+
+    /// Rebind many references from one symbol to another.
+    ///
+    /// All references must currently be bound to `old_symbol_id`. Panics if not.
+    ///
+    /// This is a shortcut for `ctx.scoping.rebind_references`.
+    pub fn rebind_references(
+        &mut self,
+        reference_ids: &[ReferenceId],
+        old_symbol_id: SymbolId,
+        new_symbol_id: SymbolId,
+    ) {
+        self.scoping.rebind_references(reference_ids, old_symbol_id, new_symbol_id);
+    }
+
     /// Rename symbol.
     ///
     /// Preserves original order of bindings for scope.
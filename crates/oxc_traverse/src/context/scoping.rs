@@ -5,6 +5,7 @@ use itoa::Buffer as ItoaBuffer;
 use rustc_hash::FxHashSet;
 
 use oxc_ast::{ast::*, visit::Visit};
+use oxc_ecmascript::BoundNames;
 use oxc_semantic::{NodeId, Reference, ScopeTree, SymbolTable};
 use oxc_span::{CompactStr, SPAN};
 use oxc_syntax::{
@@ -25,6 +26,7 @@ pub struct TraverseScoping {
     scopes: ScopeTree,
     symbols: SymbolTable,
     uid_names: Option<FxHashSet<CompactStr>>,
+    exported_symbols: Option<FxHashSet<SymbolId>>,
     current_scope_id: ScopeId,
     current_hoist_scope_id: ScopeId,
     current_block_scope_id: ScopeId,
@@ -80,6 +82,86 @@ impl TraverseScoping {
         &mut self.symbols
     }
 
+    /// Check if a symbol is exported from the module.
+    ///
+    /// Exports are determined from export statements at top level of program, when
+    /// [`Self::collect_exported_symbols`] is called. Exports added after that are not reflected.
+    ///
+    /// Exports are not collected automatically, so that traversals which don't need them don't pay
+    /// the cost. Any transform which uses this method must call [`Self::collect_exported_symbols`]
+    /// in its `enter_program` visitor first.
+    ///
+    /// Covers:
+    /// * `export class C {}`, `export function f() {}`, `export let x, y;`
+    /// * `export { x }`, `export { x as y }`
+    /// * `export default class C {}`, `export default function f() {}`, `export default x`
+    /// * `export = x` (TypeScript)
+    #[inline]
+    pub fn symbol_is_exported(&self, symbol_id: SymbolId) -> bool {
+        debug_assert!(
+            self.exported_symbols.is_some(),
+            "`collect_exported_symbols` must be called before `symbol_is_exported`"
+        );
+        self.exported_symbols.as_ref().is_some_and(|symbols| symbols.contains(&symbol_id))
+    }
+
+    /// Collect symbols which are exported from the module, for use by [`Self::symbol_is_exported`].
+    ///
+    /// Only the first call in each traversal does any work, so multiple transforms can each call this
+    /// in their `enter_program` visitor without scanning the program repeatedly.
+    pub fn collect_exported_symbols(&mut self, program: &Program<'_>) {
+        if self.exported_symbols.is_some() {
+            return;
+        }
+
+        let symbols = &self.symbols;
+        let mut exported_symbols = FxHashSet::default();
+
+        let mut add = |symbol_id: Option<SymbolId>| {
+            if let Some(symbol_id) = symbol_id {
+                exported_symbols.insert(symbol_id);
+            }
+        };
+        let reference_symbol_id = |ident: &IdentifierReference<'_>| {
+            ident.reference_id.get().and_then(|id| symbols.get_reference(id).symbol_id())
+        };
+
+        for stmt in &program.body {
+            match stmt {
+                Statement::ExportNamedDeclaration(decl) => {
+                    decl.bound_names(&mut |ident| add(ident.symbol_id.get()));
+                    if decl.source.is_none() {
+                        for specifier in &decl.specifiers {
+                            if let ModuleExportName::IdentifierReference(ident) = &specifier.local {
+                                add(reference_symbol_id(ident));
+                            }
+                        }
+                    }
+                }
+                Statement::ExportDefaultDeclaration(decl) => match &decl.declaration {
+                    ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                        add(class.id.as_ref().and_then(|ident| ident.symbol_id.get()));
+                    }
+                    ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                        add(func.id.as_ref().and_then(|ident| ident.symbol_id.get()));
+                    }
+                    ExportDefaultDeclarationKind::Identifier(ident) => {
+                        add(reference_symbol_id(ident));
+                    }
+                    _ => {}
+                },
+                Statement::TSExportAssignment(assign) => {
+                    if let Expression::Identifier(ident) = &assign.expression {
+                        add(reference_symbol_id(ident));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        self.exported_symbols = Some(exported_symbols);
+    }
+
     /// Get iterator over scopes, starting with current scope and working up
     pub fn ancestor_scopes(&self) -> impl Iterator<Item = ScopeId> + '_ {
         self.scopes.ancestors(self.current_scope_id)
@@ -364,6 +446,46 @@ impl TraverseScoping {
         self.delete_reference(ident.reference_id(), &ident.name);
     }
 
+    /// Rebind a reference from one symbol to another.
+    ///
+    /// Sets `SymbolId` of the `Reference` to `new_symbol_id`, and moves it from resolved references
+    /// of `old_symbol_id` to resolved references of `new_symbol_id`.
+    ///
+    /// Reference must currently be bound to `old_symbol_id`. Panics in debug mode if not.
+    pub fn rebind_reference(
+        &mut self,
+        reference_id: ReferenceId,
+        old_symbol_id: SymbolId,
+        new_symbol_id: SymbolId,
+    ) {
+        let reference = self.symbols.get_reference_mut(reference_id);
+        debug_assert_eq!(reference.symbol_id(), Some(old_symbol_id));
+        reference.set_symbol_id(new_symbol_id);
+        self.symbols.delete_resolved_reference(old_symbol_id, reference_id);
+        self.symbols.add_resolved_reference(new_symbol_id, reference_id);
+    }
+
+    /// Rebind many references from one symbol to another.
+    ///
+    /// Equivalent to calling [`TraverseScoping::rebind_reference`] for each reference, but only
+    /// searches resolved references of `old_symbol_id` once, so is much faster when rebinding
+    /// many references to same symbol.
+    ///
+    /// All references must currently be bound to `old_symbol_id`. Panics if not.
+    pub fn rebind_references(
+        &mut self,
+        reference_ids: &[ReferenceId],
+        old_symbol_id: SymbolId,
+        new_symbol_id: SymbolId,
+    ) {
+        for &reference_id in reference_ids {
+            let reference = self.symbols.get_reference_mut(reference_id);
+            debug_assert_eq!(reference.symbol_id(), Some(old_symbol_id));
+            reference.set_symbol_id(new_symbol_id);
+        }
+        self.symbols.move_resolved_references(old_symbol_id, new_symbol_id, reference_ids);
+    }
+
     /// Rename symbol.
     ///
     /// The following must be true for successful operation:
@@ -388,6 +510,7 @@ impl TraverseScoping {
             scopes,
             symbols,
             uid_names: None,
+            exported_symbols: None,
             // Dummy values. Both immediately overwritten in `walk_program`.
             current_scope_id: ScopeId::new(0),
             current_hoist_scope_id: ScopeId::new(0),
@@ -400,6 +523,14 @@ impl TraverseScoping {
         (self.symbols, self.scopes)
     }
 
+    /// Discard exports collected in a previous traversal.
+    ///
+    /// Called before start of traversal, as the AST may have changed since the last traversal.
+    #[inline]
+    pub(crate) fn reset_exported_symbols(&mut self) {
+        self.exported_symbols = None;
+    }
+
     /// Set current scope ID
     #[inline]
     pub(crate) fn set_current_scope_id(&mut self, scope_id: ScopeId) {
@@ -695,3 +826,153 @@ fn test_get_unique_name() {
         assert_eq!(get_unique_name(name, &used), expected);
     }
 }
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::{Class, IdentifierReference, Program};
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use oxc_syntax::{reference::ReferenceId, symbol::SymbolId};
+
+    use crate::{Traverse, TraverseCtx, traverse_mut};
+
+    struct ClassExports(Vec<(String, bool)>);
+
+    impl<'a> Traverse<'a> for ClassExports {
+        fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+            ctx.collect_exported_symbols(program);
+        }
+
+        fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+            let ident = class.id.as_ref().unwrap();
+            self.0.push((ident.name.to_string(), ctx.symbol_is_exported(ident.symbol_id())));
+        }
+    }
+
+    #[test]
+    fn symbol_is_exported() {
+        let source = "
+            export class A {}
+            class B {}
+            export { B };
+            export default class C {}
+            class D {}
+            class E {}
+            export { E as EE };
+            {
+                class B {}
+            }
+        ";
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, SourceType::mjs()).parse();
+        let mut program = ret.program;
+        let (symbols, scopes) =
+            SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+
+        let mut traverser = ClassExports(vec![]);
+        traverse_mut(&mut traverser, &allocator, &mut program, symbols, scopes);
+
+        let expected =
+            [("A", true), ("B", true), ("C", true), ("D", false), ("E", true), ("B", false)]
+                .map(|(name, exported)| (name.to_string(), exported));
+        assert_eq!(traverser.0, expected);
+    }
+
+    struct RebindReferences {
+        old_symbol_id: SymbolId,
+        new_symbol_id: SymbolId,
+    }
+
+    impl<'a> Traverse<'a> for RebindReferences {
+        fn enter_identifier_reference(
+            &mut self,
+            ident: &mut IdentifierReference<'a>,
+            ctx: &mut TraverseCtx<'a>,
+        ) {
+            if ident.name == "a" {
+                ident.name = "b".into();
+                ctx.rebind_reference(ident.reference_id(), self.old_symbol_id, self.new_symbol_id);
+            }
+        }
+    }
+
+    #[test]
+    fn rebind_reference() {
+        let source = "let a, b; a; a = 1; b; { a; }";
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, SourceType::mjs()).parse();
+        let mut program = ret.program;
+        let (symbols, scopes) =
+            SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+
+        let root_scope_id = scopes.root_scope_id();
+        let old_symbol_id = scopes.get_binding(root_scope_id, "a").unwrap();
+        let new_symbol_id = scopes.get_binding(root_scope_id, "b").unwrap();
+
+        let mut traverser = RebindReferences { old_symbol_id, new_symbol_id };
+        let (symbols, _scopes) =
+            traverse_mut(&mut traverser, &allocator, &mut program, symbols, scopes);
+
+        assert!(symbols.get_resolved_reference_ids(old_symbol_id).is_empty());
+        let reference_ids = symbols.get_resolved_reference_ids(new_symbol_id);
+        assert_eq!(reference_ids.len(), 4);
+        for &reference_id in reference_ids {
+            assert_eq!(symbols.get_reference(reference_id).symbol_id(), Some(new_symbol_id));
+        }
+        assert!(
+            symbols.references.iter().all(|reference| reference.symbol_id() != Some(old_symbol_id))
+        );
+    }
+
+    struct RebindReferencesBatched {
+        old_symbol_id: SymbolId,
+        new_symbol_id: SymbolId,
+        reference_ids: Vec<ReferenceId>,
+    }
+
+    impl<'a> Traverse<'a> for RebindReferencesBatched {
+        fn enter_identifier_reference(
+            &mut self,
+            ident: &mut IdentifierReference<'a>,
+            _ctx: &mut TraverseCtx<'a>,
+        ) {
+            if ident.name == "a" {
+                ident.name = "b".into();
+                self.reference_ids.push(ident.reference_id());
+            }
+        }
+
+        fn exit_program(&mut self, _program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+            ctx.rebind_references(&self.reference_ids, self.old_symbol_id, self.new_symbol_id);
+        }
+    }
+
+    #[test]
+    fn rebind_references() {
+        let source = "let a, b; a; a = 1; b; { a; }";
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, SourceType::mjs()).parse();
+        let mut program = ret.program;
+        let (symbols, scopes) =
+            SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+
+        let root_scope_id = scopes.root_scope_id();
+        let old_symbol_id = scopes.get_binding(root_scope_id, "a").unwrap();
+        let new_symbol_id = scopes.get_binding(root_scope_id, "b").unwrap();
+
+        let mut traverser =
+            RebindReferencesBatched { old_symbol_id, new_symbol_id, reference_ids: vec![] };
+        let (symbols, _scopes) =
+            traverse_mut(&mut traverser, &allocator, &mut program, symbols, scopes);
+
+        assert!(symbols.get_resolved_reference_ids(old_symbol_id).is_empty());
+        let reference_ids = symbols.get_resolved_reference_ids(new_symbol_id);
+        assert_eq!(reference_ids.len(), 4);
+        for &reference_id in reference_ids {
+            assert_eq!(symbols.get_reference(reference_id).symbol_id(), Some(new_symbol_id));
+        }
+    }
+}
@@ -170,10 +170,12 @@ pub fn traverse_mut_with_ctx<'a, Tr: Traverse<'a>>(
     program: &mut Program<'a>,
     ctx: &mut ReusableTraverseCtx<'a>,
 ) {
-    let program = ptr::from_mut(program);
-
     let ctx = ctx.get_mut();
 
+    ctx.scoping.reset_exported_symbols();
+
+    let program = ptr::from_mut(program);
+
     // Check that `TraverseAncestry`'s stack is in correct state
     debug_assert!(ctx.ancestors_depth() == 1);
     debug_assert!(matches!(ctx.parent(), Ancestor::None));
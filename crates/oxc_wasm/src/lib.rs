@@ -206,6 +206,7 @@ impl Oxc {
             preserve_parens: parser_options
                 .preserve_parens
                 .unwrap_or(default_parser_options.preserve_parens),
+            ..default_parser_options
         };
         let ParserReturn { mut program, errors, module_record, .. } =
             Parser::new(&allocator, source_text, source_type)
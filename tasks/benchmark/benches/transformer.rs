@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::{fmt::Write, path::Path};
 
 use oxc_allocator::Allocator;
 use oxc_benchmark::{BenchmarkId, Criterion, criterion_group, criterion_main};
@@ -6,7 +6,7 @@ use oxc_parser::{Parser, ParserReturn};
 use oxc_semantic::SemanticBuilder;
 use oxc_span::SourceType;
 use oxc_tasks_common::TestFiles;
-use oxc_transformer::{EnvOptions, TransformOptions, Transformer};
+use oxc_transformer::{ESTarget, EnvOptions, TransformOptions, Transformer};
 
 fn bench_transformer(criterion: &mut Criterion) {
     let mut group = criterion.benchmark_group("transformer");
@@ -64,5 +64,261 @@ fn bench_transformer(criterion: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(transformer, bench_transformer);
+/// Benchmark class properties transform of a static property initializer which requires walking the
+/// whole initializer (`walk_deep == true`), because class has a name. The initializer contains
+/// many nested functions, references to class name, and `super` accesses.
+///
+/// Parameterized by number of repetitions of this code in the initializer, to show scaling behavior.
+fn bench_transformer_static_initializer(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("transformer_static_initializer");
+
+    // Only class properties transform (and nothing else) is required for this source
+    let transform_options = TransformOptions::from(ESTarget::ES2021);
+    let source_type = SourceType::mjs();
+
+    for count in [10, 100, 1000] {
+        let id = BenchmarkId::from_parameter(count);
+        let elements = "() => [C, super.x, function () { return C.y; }],\n".repeat(count);
+        let source_text = format!("class C extends S {{\nstatic x = [\n{elements}];\n}}\n");
+
+        let mut allocator = Allocator::default();
+
+        group.bench_function(id, |b| {
+            b.iter_with_setup_wrapper(|runner| {
+                allocator.reset();
+
+                let ParserReturn { mut program, .. } =
+                    Parser::new(&allocator, &source_text, source_type).parse();
+                let (symbols, scopes) = SemanticBuilder::new()
+                    .with_excess_capacity(2.0)
+                    .build(&program)
+                    .semantic
+                    .into_symbol_table_and_scope_tree();
+
+                runner.run(|| {
+                    Transformer::new(&allocator, Path::new("test.mjs"), &transform_options)
+                        .build_with_symbols_and_scopes(symbols, scopes, &mut program)
+                });
+            });
+        });
+    }
+
+    group.finish();
+}
+
+/// Benchmark class properties transform of a file containing many classes with static properties.
+fn bench_transformer_many_classes(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("transformer_many_classes");
+
+    let transform_options = TransformOptions::from(ESTarget::ES2021);
+    let source_type = SourceType::mjs();
+
+    let count = 500;
+    let id = BenchmarkId::from_parameter(count);
+    let source_text = (0..count).fold(String::new(), |mut source_text, i| {
+        writeln!(
+            source_text,
+            "class C{i} {{ static x = this; static y = C{i}.x; static {{ f(this); }} }}"
+        )
+        .unwrap();
+        source_text
+    });
+
+    let mut allocator = Allocator::default();
+
+    group.bench_function(id, |b| {
+        b.iter_with_setup_wrapper(|runner| {
+            allocator.reset();
+
+            let ParserReturn { mut program, .. } =
+                Parser::new(&allocator, &source_text, source_type).parse();
+            let (symbols, scopes) = SemanticBuilder::new()
+                .with_excess_capacity(2.0)
+                .build(&program)
+                .semantic
+                .into_symbol_table_and_scope_tree();
+
+            runner.run(|| {
+                Transformer::new(&allocator, Path::new("test.mjs"), &transform_options)
+                    .build_with_symbols_and_scopes(symbols, scopes, &mut program)
+            });
+        });
+    });
+
+    group.finish();
+}
+
+/// Benchmark class properties transform of a static property initializer containing many references
+/// to class name, which are all renamed to temp var.
+fn bench_transformer_class_name_references(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("transformer_class_name_references");
+
+    let transform_options = TransformOptions::from(ESTarget::ES2021);
+    let source_type = SourceType::mjs();
+
+    let count = 5000;
+    let id = BenchmarkId::from_parameter(count);
+    let source_text = format!("class C {{ static x = [{}]; }}", "C, ".repeat(count));
+
+    let mut allocator = Allocator::default();
+
+    group.bench_function(id, |b| {
+        b.iter_with_setup_wrapper(|runner| {
+            allocator.reset();
+
+            let ParserReturn { mut program, .. } =
+                Parser::new(&allocator, &source_text, source_type).parse();
+            let (symbols, scopes) = SemanticBuilder::new()
+                .with_excess_capacity(2.0)
+                .build(&program)
+                .semantic
+                .into_symbol_table_and_scope_tree();
+
+            runner.run(|| {
+                Transformer::new(&allocator, Path::new("test.mjs"), &transform_options)
+                    .build_with_symbols_and_scopes(symbols, scopes, &mut program)
+            });
+        });
+    });
+
+    group.finish();
+}
+
+/// Benchmark class properties transform of TypeScript static property initializers containing
+/// many types, with `skipTsTypesInStaticInitializers` option off and on.
+///
+/// TypeScript transform removes types before static initializers are transformed, so difference
+/// between the two is expected to be small.
+fn bench_transformer_static_initializer_types(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("transformer_static_initializer_types");
+
+    let source_type = SourceType::ts();
+    let element =
+        "<T,>(a: T): { [K in keyof T]: T[K] extends infer U ? U : never } => C.f<T>(a as T),\n";
+    let source_text = format!("class C {{\nstatic x = [\n{}];\n}}\n", element.repeat(1000));
+
+    for skip in [false, true] {
+        let id = BenchmarkId::from_parameter(if skip { "skip" } else { "visit" });
+
+        let mut transform_options = TransformOptions::from(ESTarget::ES2021);
+        transform_options.env.es2022.class_properties = Some(
+            serde_json::from_value(serde_json::json!({ "skipTsTypesInStaticInitializers": skip }))
+                .unwrap(),
+        );
+
+        let mut allocator = Allocator::default();
+
+        group.bench_function(id, |b| {
+            b.iter_with_setup_wrapper(|runner| {
+                allocator.reset();
+
+                let ParserReturn { mut program, .. } =
+                    Parser::new(&allocator, &source_text, source_type).parse();
+                let (symbols, scopes) = SemanticBuilder::new()
+                    .with_excess_capacity(2.0)
+                    .build(&program)
+                    .semantic
+                    .into_symbol_table_and_scope_tree();
+
+                runner.run(|| {
+                    Transformer::new(&allocator, Path::new("test.ts"), &transform_options)
+                        .build_with_symbols_and_scopes(symbols, scopes, &mut program)
+                });
+            });
+        });
+    }
+
+    group.finish();
+}
+
+/// Benchmark class properties transform of a class with many private static properties,
+/// which are each lowered to a temp var after the class.
+fn bench_transformer_private_static_fields(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("transformer_private_static_fields");
+
+    let transform_options = TransformOptions::from(ESTarget::ES2021);
+    let source_type = SourceType::mjs();
+
+    let count = 50;
+    let id = BenchmarkId::from_parameter(count);
+    let mut source_text = String::from("class C {\n");
+    for i in 0..count {
+        writeln!(source_text, "static #p{i} = {i}; static get{i}() {{ return C.#p{i}; }}").unwrap();
+    }
+    source_text.push_str("}\n");
+
+    let mut allocator = Allocator::default();
+
+    group.bench_function(id, |b| {
+        b.iter_with_setup_wrapper(|runner| {
+            allocator.reset();
+
+            let ParserReturn { mut program, .. } =
+                Parser::new(&allocator, &source_text, source_type).parse();
+            let (symbols, scopes) = SemanticBuilder::new()
+                .with_excess_capacity(2.0)
+                .build(&program)
+                .semantic
+                .into_symbol_table_and_scope_tree();
+
+            runner.run(|| {
+                Transformer::new(&allocator, Path::new("test.mjs"), &transform_options)
+                    .build_with_symbols_and_scopes(symbols, scopes, &mut program)
+            });
+        });
+    });
+
+    group.finish();
+}
+
+/// Benchmark class properties transform of a class with many methods and no properties,
+/// which requires no transform.
+fn bench_transformer_class_methods_only(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("transformer_class_methods_only");
+
+    let transform_options = TransformOptions::from(ESTarget::ES2021);
+    let source_type = SourceType::mjs();
+
+    let count = 1000;
+    let id = BenchmarkId::from_parameter(count);
+    let mut source_text = String::from("class C {\n");
+    for i in 0..count {
+        writeln!(source_text, "static s{i}() {{ return this; }} m{i}() {{ return C; }}").unwrap();
+    }
+    source_text.push_str("}\n");
+
+    let mut allocator = Allocator::default();
+
+    group.bench_function(id, |b| {
+        b.iter_with_setup_wrapper(|runner| {
+            allocator.reset();
+
+            let ParserReturn { mut program, .. } =
+                Parser::new(&allocator, &source_text, source_type).parse();
+            let (symbols, scopes) = SemanticBuilder::new()
+                .with_excess_capacity(2.0)
+                .build(&program)
+                .semantic
+                .into_symbol_table_and_scope_tree();
+
+            runner.run(|| {
+                Transformer::new(&allocator, Path::new("test.mjs"), &transform_options)
+                    .build_with_symbols_and_scopes(symbols, scopes, &mut program)
+            });
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    transformer,
+    bench_transformer,
+    bench_transformer_static_initializer,
+    bench_transformer_many_classes,
+    bench_transformer_class_name_references,
+    bench_transformer_static_initializer_types,
+    bench_transformer_private_static_fields,
+    bench_transformer_class_methods_only
+);
 criterion_main!(transformer);
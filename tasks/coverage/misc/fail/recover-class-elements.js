@@ -0,0 +1,11 @@
+class C {
+  a = 1 b = 2;
+  c = ;
+  method() { x y }
+  static d = 3 +;
+  static [e] = 4
+  get f() { return 5; }
+  g = (1 2)
+  static h = { i: 6 }
+}
+foo();
@@ -30,6 +30,7 @@ pub struct Driver {
     pub codegen: bool,
     pub check_semantic: bool,
     pub allow_return_outside_function: bool,
+    pub recover_class_elements: bool,
     // results
     pub panicked: bool,
     pub errors: Vec<OxcDiagnostic>,
@@ -41,6 +42,7 @@ impl CompilerInterface for Driver {
         ParseOptions {
             parse_regular_expression: true,
             allow_return_outside_function: self.allow_return_outside_function,
+            recover_class_elements: self.recover_class_elements,
             ..ParseOptions::default()
         }
     }
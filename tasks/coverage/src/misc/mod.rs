@@ -95,6 +95,13 @@ impl Case for MiscCase {
         self.should_fail
     }
 
+    /// Fixtures named `recover-class-elements*` are parsed with `recover_class_elements` option.
+    fn recover_class_elements(&self) -> bool {
+        self.path
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with("recover-class-elements"))
+    }
+
     fn run(&mut self) {
         let result = self.execute(self.source_type);
         self.set_result(result);
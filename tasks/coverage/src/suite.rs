@@ -273,6 +273,9 @@ pub trait Case: Sized + Sync + Send + UnwindSafe {
     fn allow_return_outside_function(&self) -> bool {
         false
     }
+    fn recover_class_elements(&self) -> bool {
+        false
+    }
     fn test_result(&self) -> &TestResult;
 
     fn should_fail(&self) -> bool {
@@ -321,6 +324,7 @@ pub trait Case: Sized + Sync + Send + UnwindSafe {
         let mut driver = Driver {
             path: path.to_path_buf(),
             allow_return_outside_function: self.allow_return_outside_function(),
+            recover_class_elements: self.recover_class_elements(),
             ..Driver::default()
         };
 
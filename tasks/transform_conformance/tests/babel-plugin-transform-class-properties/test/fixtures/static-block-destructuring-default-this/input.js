@@ -0,0 +1,10 @@
+class C {
+  static {
+    const { a = this.a, b: [c = C.c] = [this.b], ...rest } = obj;
+    let d, f;
+    ({ d = this.d, e: [f = C.f] } = obj);
+    for (const { g = this.g } of list);
+    try {} catch ({ h = this.h }) {}
+  }
+  static p = ({ q = this.q } = obj, [r = C.r] = []) => q;
+}
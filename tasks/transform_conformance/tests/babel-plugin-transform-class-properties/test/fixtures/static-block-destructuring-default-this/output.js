@@ -0,0 +1,12 @@
+var _C;
+
+class C {}
+_C = C;
+(() => {
+  const { a = _C.a, b: [c = _C.c] = [_C.b], ...rest } = obj;
+  let d, f;
+  ({ d = _C.d, e: [f = _C.f] } = obj);
+  for (const { g = _C.g } of list);
+  try {} catch ({ h = _C.h }) {}
+})();
+babelHelpers.defineProperty(C, "p", ({ q = _C.q } = obj, [r = _C.r] = []) => q);
@@ -0,0 +1,18 @@
+class C {
+  static {
+    outer: for (;;) {
+      inner: for (;;) {
+        if (this.done) break outer;
+        if (C.skip) continue inner;
+        continue outer;
+      }
+    }
+  }
+
+  static {
+    block: {
+      if (this.x) break block;
+      this.y();
+    }
+  }
+}
@@ -0,0 +1,21 @@
+var _C;
+
+class C {}
+_C = C;
+
+(() => {
+  outer: for (;;) {
+    inner: for (;;) {
+      if (_C.done) break outer;
+      if (_C.skip) continue inner;
+      continue outer;
+    }
+  }
+})();
+
+(() => {
+  block: {
+    if (_C.x) break block;
+    _C.y();
+  }
+})();
@@ -0,0 +1,24 @@
+class C {
+  static {
+    this.gen = function*() {
+      yield this;
+      yield* this.inner();
+    };
+    this.asyncGen = async function*() {
+      yield await this;
+    };
+    this.self = this;
+  }
+}
+
+let D = class {
+  static {
+    this.gen = function*() {
+      yield this;
+    };
+    this.arrowGen = () => function*() {
+      yield this;
+    };
+    this.self = this;
+  }
+};
@@ -0,0 +1,24 @@
+var _C, _Class;
+
+class C {}
+_C = C;
+(() => {
+  _C.gen = function*() {
+    yield this;
+    yield* this.inner();
+  };
+  _C.asyncGen = async function*() {
+    yield await this;
+  };
+  _C.self = _C;
+})();
+
+let D = (_Class = class {}, (() => {
+  _Class.gen = function*() {
+    yield this;
+  };
+  _Class.arrowGen = () => function*() {
+    yield this;
+  };
+  _Class.self = _Class;
+})(), _Class);
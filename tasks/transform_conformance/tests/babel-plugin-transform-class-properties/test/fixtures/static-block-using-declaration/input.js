@@ -0,0 +1,19 @@
+class C {
+  static {
+    using r = this.resource();
+    r.use(C);
+  }
+
+  static {
+    (async () => {
+      await using r = this.asyncResource();
+      await using s = C.asyncResource();
+    })();
+  }
+
+  static {
+    (function() {
+      using r = this.resource();
+    })();
+  }
+}
@@ -0,0 +1,18 @@
+var _C;
+
+class C {}
+_C = C;
+
+(() => {
+  using r = _C.resource();
+  r.use(_C);
+})();
+
+(async () => {
+  await using r = _C.asyncResource();
+  await using s = _C.asyncResource();
+})();
+
+(function() {
+  using r = this.resource();
+})();
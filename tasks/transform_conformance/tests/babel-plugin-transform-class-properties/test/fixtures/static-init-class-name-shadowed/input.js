@@ -0,0 +1,8 @@
+class C {
+  static x = (C) => C;
+  static y = () => {
+    const C = 1;
+    return C;
+  };
+  static z = C;
+}
@@ -0,0 +1,9 @@
+var _C;
+class C {}
+_C = C;
+babelHelpers.defineProperty(C, "x", (C) => C);
+babelHelpers.defineProperty(C, "y", () => {
+  const C = 1;
+  return C;
+});
+babelHelpers.defineProperty(C, "z", _C);
@@ -0,0 +1,4 @@
+class C {
+  static x = C;
+  static y = () => C.x;
+}
@@ -0,0 +1,5 @@
+class C {}
+babelHelpers.defineProperty(C, "x", 1);
+babelHelpers.defineProperty(C, "y", function () {
+  return this;
+});
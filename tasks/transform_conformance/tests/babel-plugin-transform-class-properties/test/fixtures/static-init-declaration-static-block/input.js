@@ -0,0 +1,6 @@
+class C {
+  static x = 1;
+  static {
+    this.y = C.x;
+  }
+}
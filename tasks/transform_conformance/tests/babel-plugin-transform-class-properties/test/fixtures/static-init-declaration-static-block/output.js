@@ -0,0 +1,5 @@
+var _C;
+class C {}
+_C = C;
+babelHelpers.defineProperty(C, "x", 1);
+_C.y = _C.x;
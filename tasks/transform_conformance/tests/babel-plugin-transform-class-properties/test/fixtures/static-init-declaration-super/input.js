@@ -0,0 +1,5 @@
+class C extends B {
+  static x = super.x;
+  static y = super.method();
+  static z = () => super[key];
+}
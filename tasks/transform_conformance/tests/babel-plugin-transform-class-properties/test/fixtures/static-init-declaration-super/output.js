@@ -0,0 +1,10 @@
+var _C;
+class C extends B {}
+_C = C;
+babelHelpers.defineProperty(C, "x", babelHelpers.superPropGet(_C, "x", _C));
+babelHelpers.defineProperty(
+  C,
+  "y",
+  babelHelpers.superPropGet(_C, "method", _C, 2)([]),
+);
+babelHelpers.defineProperty(C, "z", () => babelHelpers.superPropGet(_C, key, _C));
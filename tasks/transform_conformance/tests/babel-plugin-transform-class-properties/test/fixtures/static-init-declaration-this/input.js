@@ -0,0 +1,4 @@
+class C {
+  static x = this;
+  static y = this.x;
+}
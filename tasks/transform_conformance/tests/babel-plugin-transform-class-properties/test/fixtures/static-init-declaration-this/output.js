@@ -0,0 +1,5 @@
+var _C;
+class C {}
+_C = C;
+babelHelpers.defineProperty(C, "x", _C);
+babelHelpers.defineProperty(C, "y", _C.x);
@@ -0,0 +1,3 @@
+const x = class {
+  static y = 1;
+};
@@ -0,0 +1,2 @@
+var _Class;
+const x = ((_Class = class {}), babelHelpers.defineProperty(_Class, "y", 1), _Class);
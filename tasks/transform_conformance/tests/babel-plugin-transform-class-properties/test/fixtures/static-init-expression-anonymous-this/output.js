@@ -0,0 +1,3 @@
+var _Class;
+const x =
+  ((_Class = class {}), babelHelpers.defineProperty(_Class, "y", _Class), _Class);
@@ -0,0 +1,3 @@
+const x = class C {
+  static y = this;
+};
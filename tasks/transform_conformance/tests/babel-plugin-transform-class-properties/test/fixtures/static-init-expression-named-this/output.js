@@ -0,0 +1,2 @@
+var _C;
+const x = ((_C = class C {}), babelHelpers.defineProperty(_C, "y", _C), _C);
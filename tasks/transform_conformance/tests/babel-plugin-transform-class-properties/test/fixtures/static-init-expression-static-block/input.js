@@ -0,0 +1,6 @@
+const x = class {
+  static {
+    this.y = 1;
+    this.z = 2;
+  }
+};
@@ -0,0 +1,8 @@
+var _Class;
+const x =
+  ((_Class = class {}),
+  (() => {
+    _Class.y = 1;
+    _Class.z = 2;
+  })(),
+  _Class);
@@ -0,0 +1,4 @@
+const x = class extends B {
+  static y = super.y;
+  static z = super.method(1, 2);
+};
@@ -0,0 +1,14 @@
+var _Class;
+const x =
+  ((_Class = class extends B {}),
+  babelHelpers.defineProperty(
+    _Class,
+    "y",
+    babelHelpers.superPropGet(_Class, "y", _Class),
+  ),
+  babelHelpers.defineProperty(
+    _Class,
+    "z",
+    babelHelpers.superPropGet(_Class, "method", _Class, 2)([1, 2]),
+  ),
+  _Class);
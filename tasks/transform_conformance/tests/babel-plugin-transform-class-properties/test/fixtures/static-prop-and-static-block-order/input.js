@@ -0,0 +1,15 @@
+class C {
+  static a = log(1);
+  static {
+    log(2);
+  }
+  static b = log(3);
+}
+
+x = class {
+  static a = log(1);
+  static {
+    log(2);
+  }
+  static b = log(3);
+};
@@ -0,0 +1,14 @@
+var _Class;
+
+class C {}
+babelHelpers.defineProperty(C, "a", log(1));
+log(2);
+babelHelpers.defineProperty(C, "b", log(3));
+
+x = (
+  (_Class = class {}),
+  babelHelpers.defineProperty(_Class, "a", log(1)),
+  log(2),
+  babelHelpers.defineProperty(_Class, "b", log(3)),
+  _Class
+);
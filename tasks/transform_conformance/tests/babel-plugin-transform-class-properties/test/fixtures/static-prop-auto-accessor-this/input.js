@@ -0,0 +1,8 @@
+class C {
+  static init = 1;
+  static accessor x = this.init;
+  static y = class {
+    static init = 2;
+    static accessor [this.init] = this.init;
+  };
+}
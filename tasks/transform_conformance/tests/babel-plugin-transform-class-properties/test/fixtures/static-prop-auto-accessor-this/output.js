@@ -0,0 +1,9 @@
+var _Class, _C;
+class C {
+  static accessor x = this.init;
+}
+_C = C;
+babelHelpers.defineProperty(C, "init", 1);
+babelHelpers.defineProperty(C, "y", (_Class = class {
+  static accessor [_C.init] = this.init;
+}, babelHelpers.defineProperty(_Class, "init", 2), _Class));
@@ -0,0 +1,20 @@
+const D = class C {
+  static self = this;
+  static ref = C;
+  static name2 = C.name;
+};
+
+expect(D.name).toBe("C");
+expect(D.self.name).toBe("C");
+expect(D.ref.name).toBe("C");
+expect(D.name2).toBe("C");
+
+class F {
+  static self = this;
+  static ref = F;
+}
+const G = F;
+F = null;
+expect(G.name).toBe("F");
+expect(G.self.name).toBe("F");
+expect(G.ref.name).toBe("F");
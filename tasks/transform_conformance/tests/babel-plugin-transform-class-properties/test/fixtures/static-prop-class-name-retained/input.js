@@ -0,0 +1,11 @@
+const D = class C {
+  static self = this;
+  static ref = C;
+  static name2 = C.name;
+};
+
+class E {
+  static self = this;
+  static ref = E;
+}
+E = null;
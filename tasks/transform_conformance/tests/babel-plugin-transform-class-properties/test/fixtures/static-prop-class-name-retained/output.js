@@ -0,0 +1,14 @@
+var _C, _E;
+
+const D =
+  ((_C = class C {}),
+  babelHelpers.defineProperty(_C, "self", _C),
+  babelHelpers.defineProperty(_C, "ref", _C),
+  babelHelpers.defineProperty(_C, "name2", _C.name),
+  _C);
+
+class E {}
+_E = E;
+babelHelpers.defineProperty(E, "self", _E);
+babelHelpers.defineProperty(E, "ref", _E);
+E = null;
@@ -0,0 +1,5 @@
+// `Object.assign` uses `[[Set]]` semantics, so assignments are only combined in loose mode
+class C {
+  static a = 1;
+  static b = 2;
+}
@@ -0,0 +1,4 @@
+// `Object.assign` uses `[[Set]]` semantics, so assignments are only combined in loose mode
+class C {}
+babelHelpers.defineProperty(C, "a", 1);
+babelHelpers.defineProperty(C, "b", 2);
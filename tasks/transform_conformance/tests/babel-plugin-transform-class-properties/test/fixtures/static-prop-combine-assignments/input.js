@@ -0,0 +1,55 @@
+class A {
+  static a = 1;
+  static b = "b";
+  static c = null;
+  static d;
+}
+
+class B {
+  static a = 1;
+  static b = f();
+  static c = 2;
+  static d = true;
+  static {
+    log(B.d);
+  }
+  static e = 3n;
+  static f = /x/;
+}
+
+class C {
+  static a = 1;
+  static a = 2;
+  static b = 3;
+  static __proto__ = 4;
+  static ["c"] = 5;
+  static d = 6;
+  static "e" = 7;
+  static 0 = 8;
+  static name = 9;
+  static f = 10;
+}
+
+let D = class {
+  static a = 1;
+  static b = 2;
+  static c = this;
+};
+
+class E {
+  static #p = 1;
+  static a = 1;
+  static b = 2;
+  static c = E.#p;
+}
+
+function shadowed(Object) {
+  class F {
+    static a = 1;
+    static b = 2;
+  }
+}
+
+class G {
+  static a = 1;
+}
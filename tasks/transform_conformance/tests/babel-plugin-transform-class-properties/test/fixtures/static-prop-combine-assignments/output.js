@@ -0,0 +1,67 @@
+var _B, _Class, _E;
+class A {}
+Object.assign(A, {
+  a: 1,
+  b: "b",
+  c: null,
+  d: void 0,
+});
+
+class B {}
+_B = B;
+B.a = 1;
+B.b = f();
+Object.assign(B, {
+  c: 2,
+  d: true,
+});
+log(_B.d);
+Object.assign(B, {
+  e: 3n,
+  f: /x/,
+});
+
+class C {}
+C.a = 1;
+Object.assign(C, {
+  a: 2,
+  b: 3,
+});
+C.__proto__ = 4;
+C["c"] = 5;
+C.d = 6;
+C["e"] = 7;
+C[0] = 8;
+babelHelpers.defineProperty(C, "name", 9);
+C.f = 10;
+
+let D =
+  ((_Class = class {}),
+  Object.assign(_Class, {
+    a: 1,
+    b: 2,
+  }),
+  (_Class.c = _Class),
+  _Class);
+
+var _p = babelHelpers.classPrivateFieldLooseKey("p");
+class E {}
+_E = E;
+Object.defineProperty(E, _p, {
+  writable: true,
+  value: 1,
+});
+Object.assign(E, {
+  a: 1,
+  b: 2,
+});
+E.c = babelHelpers.classPrivateFieldLooseBase(_E, _p)[_p];
+
+function shadowed(Object) {
+  class F {}
+  F.a = 1;
+  F.b = 2;
+}
+
+class G {}
+G.a = 1;
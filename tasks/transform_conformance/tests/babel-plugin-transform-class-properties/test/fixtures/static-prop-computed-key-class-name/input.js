@@ -0,0 +1,9 @@
+const KEY = "key";
+class C {
+  static [C.KEY] = C;
+  static self = class {
+    static [C.KEY] = C;
+    [C.KEY] = C;
+  };
+}
+C = null;
@@ -0,0 +1,23 @@
+var _Class, _C;
+let _C$KEY, _C$KEY2, _C$KEY3;
+const KEY = "key";
+_C$KEY3 = C.KEY;
+class C {}
+_C = C;
+babelHelpers.defineProperty(C, _C$KEY3, _C);
+babelHelpers.defineProperty(
+  C,
+  "self",
+  (
+    (_C$KEY2 = _C.KEY),
+    (_C$KEY = _C.KEY),
+    (_Class = class {
+      constructor() {
+        babelHelpers.defineProperty(this, _C$KEY, _C);
+      }
+    }),
+    babelHelpers.defineProperty(_Class, _C$KEY2, _C),
+    _Class
+  ),
+);
+C = null;
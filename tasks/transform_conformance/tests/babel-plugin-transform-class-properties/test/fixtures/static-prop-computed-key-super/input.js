@@ -0,0 +1,9 @@
+class O extends B {
+  static m() {
+    class C extends S {
+      static [super.x]() {}
+      static [super.y] = super.z;
+    }
+    return C;
+  }
+}
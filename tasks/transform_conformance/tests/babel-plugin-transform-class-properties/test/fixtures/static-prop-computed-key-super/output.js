@@ -0,0 +1,14 @@
+class O extends B {
+  static m() {
+    var _C;
+    let _super$x, _super$y;
+    _super$x = super.x;
+    _super$y = super.y;
+    class C extends S {
+      static [_super$x]() {}
+    }
+    _C = C;
+    babelHelpers.defineProperty(C, _super$y, babelHelpers.superPropGet(_C, "z", _C));
+    return C;
+  }
+}
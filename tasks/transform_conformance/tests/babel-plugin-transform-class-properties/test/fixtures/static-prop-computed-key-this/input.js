@@ -0,0 +1,11 @@
+class Outer {
+  method() {
+    class C {
+      static [Symbol.for(this.k)] = this.y;
+      static [this.k2] = () => this;
+    }
+    return class {
+      static [Symbol.for(this.k)] = this.y;
+    };
+  }
+}
@@ -0,0 +1,18 @@
+class Outer {
+  method() {
+    var _C, _Class;
+    let _Symbol$for, _this$k, _Symbol$for2;
+    _Symbol$for = Symbol.for(this.k);
+    _this$k = this.k2;
+    class C {}
+    _C = C;
+    babelHelpers.defineProperty(C, _Symbol$for, _C.y);
+    babelHelpers.defineProperty(C, _this$k, () => _C);
+    return (
+      (_Symbol$for2 = Symbol.for(this.k)),
+      (_Class = class {}),
+      babelHelpers.defineProperty(_Class, _Symbol$for2, _Class.y),
+      _Class
+    );
+  }
+}
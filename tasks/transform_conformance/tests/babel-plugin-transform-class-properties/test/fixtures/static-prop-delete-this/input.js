@@ -0,0 +1,9 @@
+x = class {
+  static a = delete this;
+  static b = () => delete this;
+  static c = function () {
+    return delete this;
+  };
+  static d = delete this.x;
+  static e = () => delete this[y];
+};
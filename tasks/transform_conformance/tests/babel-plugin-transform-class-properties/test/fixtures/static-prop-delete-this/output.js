@@ -0,0 +1,13 @@
+var _Class;
+
+x = (
+  (_Class = class {}),
+  babelHelpers.defineProperty(_Class, "a", true),
+  babelHelpers.defineProperty(_Class, "b", () => true),
+  babelHelpers.defineProperty(_Class, "c", function () {
+    return delete this;
+  }),
+  babelHelpers.defineProperty(_Class, "d", delete _Class.x),
+  babelHelpers.defineProperty(_Class, "e", () => delete _Class[y]),
+  _Class
+);
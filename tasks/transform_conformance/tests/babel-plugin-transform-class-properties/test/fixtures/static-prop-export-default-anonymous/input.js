@@ -0,0 +1,8 @@
+export default class {
+  static x = this.y;
+  static z = () => this;
+  static {
+    this.w = 1;
+  }
+}
+foo();
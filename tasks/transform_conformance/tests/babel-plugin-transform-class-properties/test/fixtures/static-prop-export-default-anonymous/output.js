@@ -0,0 +1,5 @@
+export default class _Class {}
+babelHelpers.defineProperty(_Class, "x", _Class.y);
+babelHelpers.defineProperty(_Class, "z", () => _Class);
+_Class.w = 1;
+foo();
@@ -0,0 +1,12 @@
+class C {
+  static inner = class {
+    @metadata(C) accessor x = 1;
+    @metadata(C) method() {}
+  };
+}
+
+x = class D {
+  static inner = class {
+    @metadata(D) accessor x = 1;
+  };
+};
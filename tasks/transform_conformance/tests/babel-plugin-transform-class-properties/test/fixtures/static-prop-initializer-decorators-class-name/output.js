@@ -0,0 +1,17 @@
+var _C, _D;
+
+class C {}
+_C = C;
+
+babelHelpers.defineProperty(C, "inner", class {
+  @metadata(_C) accessor x = 1;
+  @metadata(_C) method() {}
+});
+
+x = (
+  _D = class D {},
+  babelHelpers.defineProperty(_D, "inner", class {
+    @metadata(_D) accessor x = 1;
+  }),
+  _D
+);
@@ -0,0 +1,8 @@
+class Outer {
+  static prop = class Inner {
+    @dec(this) accessor y = this;
+    @dec(this) static accessor [this.key] = this;
+    @dec(this) method() {}
+    @dec(() => this) [this.key2]() {}
+  };
+}
@@ -0,0 +1,11 @@
+var _Outer;
+
+class Outer {}
+_Outer = Outer;
+
+babelHelpers.defineProperty(Outer, "prop", class Inner {
+  @dec(_Outer) accessor y = this;
+  @dec(_Outer) static accessor [_Outer.key] = this;
+  @dec(_Outer) method() {}
+  @dec(() => _Outer) [_Outer.key2]() {}
+});
@@ -0,0 +1,10 @@
+var _C;
+class C {
+  static handle() {}
+}
+_C = C;
+babelHelpers.defineProperty(
+  C,
+  "el",
+  <div onClick={() => _C.handle()}>{_C.label}</div>,
+);
@@ -0,0 +1,4 @@
+class C {
+  static el = <div onClick={() => this.handle()}>{this.label}</div>;
+  static handle() {}
+}
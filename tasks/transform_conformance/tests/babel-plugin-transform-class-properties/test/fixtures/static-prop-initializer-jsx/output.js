@@ -0,0 +1,14 @@
+import { jsx as _jsx } from "react/jsx-runtime";
+var _C;
+class C {
+  static handle() {}
+}
+_C = C;
+babelHelpers.defineProperty(
+  C,
+  "el",
+  _jsx("div", {
+    onClick: () => _C.handle(),
+    children: _C.label,
+  }),
+);
@@ -0,0 +1,15 @@
+class Outer {
+  static x = class Inner {
+    constructor() {
+      foo(this, new.target, Outer, Inner);
+      const f = () => [this, new.target, Outer];
+    }
+  };
+  static y = class extends Outer {
+    constructor() {
+      super();
+      foo(this, Outer);
+    }
+  };
+  static z = [this, Outer];
+}
@@ -0,0 +1,24 @@
+var _Outer;
+class Outer {}
+_Outer = Outer;
+babelHelpers.defineProperty(
+  Outer,
+  "x",
+  class Inner {
+    constructor() {
+      foo(this, new.target, _Outer, Inner);
+      const f = () => [this, new.target, _Outer];
+    }
+  }
+);
+babelHelpers.defineProperty(
+  Outer,
+  "y",
+  class extends _Outer {
+    constructor() {
+      super();
+      foo(this, _Outer);
+    }
+  }
+);
+babelHelpers.defineProperty(Outer, "z", [_Outer, _Outer]);
@@ -0,0 +1,24 @@
+class C {
+  static gen = function*() {
+    yield this;
+    yield* this.inner();
+  };
+  static asyncGen = async function*() {
+    yield await this;
+  };
+  static arrow = () => this;
+  static obj = {
+    *method() {
+      yield this;
+    },
+  };
+  static self = this;
+}
+
+let D = class {
+  static gen = function*() {
+    yield this;
+  };
+  static arrow = () => this;
+  static self = this;
+};
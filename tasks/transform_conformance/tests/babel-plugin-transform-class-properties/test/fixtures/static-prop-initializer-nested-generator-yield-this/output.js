@@ -0,0 +1,28 @@
+var _C, _Class;
+
+class C {}
+_C = C;
+babelHelpers.defineProperty(C, "gen", function*() {
+  yield this;
+  yield* this.inner();
+});
+babelHelpers.defineProperty(C, "asyncGen", async function*() {
+  yield await this;
+});
+babelHelpers.defineProperty(C, "arrow", () => _C);
+babelHelpers.defineProperty(C, "obj", {
+  *method() {
+    yield this;
+  },
+});
+babelHelpers.defineProperty(C, "self", _C);
+
+let D = (
+  _Class = class {},
+  babelHelpers.defineProperty(_Class, "gen", function*() {
+    yield this;
+  }),
+  babelHelpers.defineProperty(_Class, "arrow", () => _Class),
+  babelHelpers.defineProperty(_Class, "self", _Class),
+  _Class
+);
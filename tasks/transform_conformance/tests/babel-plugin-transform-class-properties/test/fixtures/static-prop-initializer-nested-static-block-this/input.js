@@ -0,0 +1,29 @@
+class Outer {
+  static x = class Inner {
+    static {
+      doSomething(this);
+    }
+  };
+
+  static y = class {
+    static {
+      doSomething(this);
+      doSomethingElse(this);
+    }
+  };
+
+  static z = class Inner2 {
+    static prop = this;
+    static {
+      doSomething(this, Outer);
+    }
+  };
+}
+
+let Anon = class {
+  static x = class Inner {
+    static {
+      doSomething(this);
+    }
+  };
+};
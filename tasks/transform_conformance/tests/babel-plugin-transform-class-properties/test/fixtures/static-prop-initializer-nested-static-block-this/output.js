@@ -0,0 +1,32 @@
+var _Inner, _Class, _Inner2, _Outer, _Class2, _Inner3;
+
+class Outer {}
+_Outer = Outer;
+
+babelHelpers.defineProperty(Outer, "x", (_Inner = class Inner {}, doSomething(_Inner), _Inner));
+
+babelHelpers.defineProperty(Outer, "y", (_Class = class {}, (() => {
+  doSomething(_Class);
+  doSomethingElse(_Class);
+})(), _Class));
+
+babelHelpers.defineProperty(
+  Outer,
+  "z",
+  (
+    _Inner2 = class Inner2 {},
+    babelHelpers.defineProperty(_Inner2, "prop", _Inner2),
+    doSomething(_Inner2, _Outer),
+    _Inner2
+  )
+);
+
+let Anon = (
+  _Class2 = class {},
+  babelHelpers.defineProperty(
+    _Class2,
+    "x",
+    (_Inner3 = class Inner {}, doSomething(_Inner3), _Inner3)
+  ),
+  _Class2
+);
@@ -0,0 +1,17 @@
+class C {
+  static a = new.target;
+  static b = () => new.target;
+  static c = function () {
+    return new.target;
+  };
+  static d = class {
+    static e = new.target;
+  };
+  static f = import.meta;
+}
+
+function outer() {
+  return class {
+    static a = new.target;
+  };
+}
@@ -0,0 +1,23 @@
+var _Class;
+
+class C {}
+babelHelpers.defineProperty(C, "a", void 0);
+babelHelpers.defineProperty(C, "b", () => void 0);
+babelHelpers.defineProperty(C, "c", function () {
+  return new.target;
+});
+babelHelpers.defineProperty(
+  C,
+  "d",
+  ((_Class = class {}), babelHelpers.defineProperty(_Class, "e", void 0), _Class)
+);
+babelHelpers.defineProperty(C, "f", import.meta);
+
+function outer() {
+  var _Class2;
+  return (
+    (_Class2 = class {}),
+    babelHelpers.defineProperty(_Class2, "a", void 0),
+    _Class2
+  );
+}
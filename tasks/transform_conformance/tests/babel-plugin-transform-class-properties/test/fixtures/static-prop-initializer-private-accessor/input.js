@@ -0,0 +1,15 @@
+class C {
+  static get #p() {
+    return 1;
+  }
+  static set #p(v) {
+    C.value = v;
+  }
+  static x = this.#p;
+  static y = (this.#p = 2);
+  static z = () => C.#p;
+  static {
+    this.#p = 3;
+    this.#p += 4;
+  }
+}
@@ -0,0 +1,23 @@
+var _C;
+class C {}
+_C = C;
+function _get_p() {
+  return 1;
+}
+function _set_p(v) {
+  _C.value = v;
+}
+babelHelpers.defineProperty(C, "x", _get_p.call(babelHelpers.assertClassBrand(_C, _C)));
+babelHelpers.defineProperty(
+  C,
+  "y",
+  (babelHelpers.toSetter(_set_p.bind(babelHelpers.assertClassBrand(_C, _C)))._ = 2),
+);
+babelHelpers.defineProperty(C, "z", () => _get_p.call(_C));
+(() => {
+  babelHelpers.toSetter(_set_p.bind(babelHelpers.assertClassBrand(_C, _C)))._ = 3;
+  _set_p.call(
+    babelHelpers.assertClassBrand(_C, _C),
+    _get_p.call(babelHelpers.assertClassBrand(_C, _C)) + 4,
+  );
+})();
@@ -0,0 +1,10 @@
+class C {
+  static #y = 1;
+  #z = 2;
+  static x = #y in this;
+  static w = #z in C;
+  static v = () => #y in this;
+  static {
+    this.u = #y in this;
+  }
+}
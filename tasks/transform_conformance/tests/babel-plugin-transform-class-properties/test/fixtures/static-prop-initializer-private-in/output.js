@@ -0,0 +1,17 @@
+var _C;
+
+var _z = new WeakMap();
+
+class C {
+  constructor() {
+    babelHelpers.classPrivateFieldInitSpec(this, _z, 2);
+  }
+}
+_C = C;
+
+var _y = { _: 1 };
+babelHelpers.defineProperty(C, "x", babelHelpers.checkInRHS(_C) === _C);
+babelHelpers.defineProperty(C, "w", _z.has(babelHelpers.checkInRHS(_C)));
+babelHelpers.defineProperty(C, "v", () => babelHelpers.checkInRHS(_C) === _C);
+
+_C.u = babelHelpers.checkInRHS(_C) === _C;
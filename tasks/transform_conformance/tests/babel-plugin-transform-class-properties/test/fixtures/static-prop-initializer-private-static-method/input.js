@@ -0,0 +1,19 @@
+class C {
+  static #m() {
+    return 1;
+  }
+  static a = this.#m();
+  static b = () => this.#m();
+  static c = function () {
+    return this.#m();
+  };
+  static d = C.#m();
+}
+
+x = class {
+  static #m() {
+    return 1;
+  }
+  static a = this.#m();
+  static b = () => this.#m();
+};
@@ -0,0 +1,29 @@
+var _C, _Class;
+
+class C {}
+_C = C;
+function _m() {
+  return 1;
+}
+babelHelpers.defineProperty(C, "a", babelHelpers.assertClassBrand(_C, _C, _m).call(_C));
+babelHelpers.defineProperty(C, "b", () => babelHelpers.assertClassBrand(_C, _C, _m).call(_C));
+babelHelpers.defineProperty(C, "c", function () {
+  return babelHelpers.assertClassBrand(_C, this, _m).call(this);
+});
+babelHelpers.defineProperty(C, "d", _m.call(_C));
+
+x = (
+  (_Class = class {}),
+  babelHelpers.defineProperty(
+    _Class,
+    "a",
+    babelHelpers.assertClassBrand(_Class, _Class, _m2).call(_Class)
+  ),
+  babelHelpers.defineProperty(_Class, "b", () =>
+    babelHelpers.assertClassBrand(_Class, _Class, _m2).call(_Class)
+  ),
+  _Class
+);
+function _m2() {
+  return 1;
+}
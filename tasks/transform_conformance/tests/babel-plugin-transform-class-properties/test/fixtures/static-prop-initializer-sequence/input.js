@@ -0,0 +1,9 @@
+class C {
+  static x = (this.a, this.b);
+  static y = (this.a, () => this, C);
+  static z = (this.a, (this.b, this.c));
+}
+
+const D = class {
+  static x = (this.a, this.b);
+};
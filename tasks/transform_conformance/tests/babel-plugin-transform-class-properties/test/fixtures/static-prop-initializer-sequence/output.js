@@ -0,0 +1,12 @@
+var _C, _Class;
+
+class C {}
+_C = C;
+babelHelpers.defineProperty(C, "x", (_C.a, _C.b));
+babelHelpers.defineProperty(C, "y", (_C.a, () => _C, _C));
+babelHelpers.defineProperty(C, "z", (_C.a, (_C.b, _C.c)));
+
+const D =
+  ((_Class = class {}),
+  babelHelpers.defineProperty(_Class, "x", (_Class.a, _Class.b)),
+  _Class);
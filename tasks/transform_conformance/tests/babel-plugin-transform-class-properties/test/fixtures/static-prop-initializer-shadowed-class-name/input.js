@@ -0,0 +1,20 @@
+class C {
+  static a = (() => {
+    let C = 1;
+    return C;
+  })();
+
+  static b = function C() {
+    return C;
+  };
+
+  static c = (C) => C;
+
+  static d = () => {
+    {
+      const C = 2;
+      C;
+    }
+    return C;
+  };
+}
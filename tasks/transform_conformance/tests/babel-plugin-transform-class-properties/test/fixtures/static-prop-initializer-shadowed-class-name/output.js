@@ -0,0 +1,23 @@
+var _C;
+
+class C {}
+_C = C;
+
+babelHelpers.defineProperty(C, "a", (() => {
+  let C = 1;
+  return C;
+})());
+
+babelHelpers.defineProperty(C, "b", function C() {
+  return C;
+});
+
+babelHelpers.defineProperty(C, "c", (C) => C);
+
+babelHelpers.defineProperty(C, "d", () => {
+  {
+    const C = 2;
+    C;
+  }
+  return _C;
+});
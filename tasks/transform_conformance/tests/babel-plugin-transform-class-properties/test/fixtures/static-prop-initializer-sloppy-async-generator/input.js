@@ -0,0 +1,30 @@
+// Just to make sure we're in sloppy mode. This is a syntax error in strict mode.
+delete x;
+
+class C {
+  static asyncGen = (async function*() {
+    if (true) {}
+    yield this;
+  })();
+
+  static gen = function*() {
+    if (true) {}
+    yield this;
+  };
+
+  static asyncFn = async function() {
+    if (true) {}
+    return () => this;
+  };
+
+  static asyncArrow = async () => {
+    if (true) {}
+    return this;
+  };
+
+  static asyncGenStrict = async function*() {
+    "use strict";
+    if (true) {}
+    yield this;
+  };
+}
@@ -0,0 +1,33 @@
+var _C;
+
+// Just to make sure we're in sloppy mode. This is a syntax error in strict mode.
+delete x;
+
+class C {}
+_C = C;
+
+babelHelpers.defineProperty(C, "asyncGen", (async function*() {
+  if (true) {}
+  yield this;
+})());
+
+babelHelpers.defineProperty(C, "gen", function*() {
+  if (true) {}
+  yield this;
+});
+
+babelHelpers.defineProperty(C, "asyncFn", async function() {
+  if (true) {}
+  return () => this;
+});
+
+babelHelpers.defineProperty(C, "asyncArrow", async () => {
+  if (true) {}
+  return _C;
+});
+
+babelHelpers.defineProperty(C, "asyncGenStrict", async function*() {
+  "use strict";
+  if (true) {}
+  yield this;
+});
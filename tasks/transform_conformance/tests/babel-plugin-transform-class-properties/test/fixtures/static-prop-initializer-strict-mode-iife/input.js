@@ -0,0 +1,18 @@
+// Just to make sure we're in sloppy mode. This is a syntax error in strict mode.
+delete x;
+
+class C {
+  static arrow = () => {
+    if (true) {}
+    return () => {};
+  };
+
+  static fn = function() {
+    if (true) {}
+    return () => {};
+  };
+
+  static self = this;
+
+  static frozen = Object.freeze({ x: 1 }).x = 2;
+}
@@ -0,0 +1,32 @@
+var _C;
+// Just to make sure we're in sloppy mode. This is a syntax error in strict mode.
+delete x;
+
+class C {}
+_C = C;
+
+babelHelpers.defineProperty(C, "arrow", (() => {
+  "use strict";
+  return () => {
+    if (true) {}
+    return () => {};
+  };
+})());
+
+babelHelpers.defineProperty(C, "fn", (() => {
+  "use strict";
+  return function() {
+    if (true) {}
+    return () => {};
+  };
+})());
+
+babelHelpers.defineProperty(C, "self", (() => {
+  "use strict";
+  return _C;
+})());
+
+babelHelpers.defineProperty(C, "frozen", (() => {
+  "use strict";
+  return Object.freeze({ x: 1 }).x = 2;
+})());
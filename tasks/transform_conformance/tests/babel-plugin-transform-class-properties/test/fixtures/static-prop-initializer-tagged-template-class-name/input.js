@@ -0,0 +1,9 @@
+class C {
+  static t = C`${C.x}`;
+  static u = this`${this.x}`;
+  static v = C.tag`a${C}b${() => C}`;
+}
+
+x = class C {
+  static t = C`${C.x}`;
+};
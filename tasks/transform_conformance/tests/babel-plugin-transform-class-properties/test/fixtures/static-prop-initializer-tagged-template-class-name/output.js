@@ -0,0 +1,13 @@
+var _C, _C2;
+
+class C {}
+_C = C;
+babelHelpers.defineProperty(C, "t", _C`${_C.x}`);
+babelHelpers.defineProperty(C, "u", _C`${_C.x}`);
+babelHelpers.defineProperty(C, "v", _C.tag`a${_C}b${() => _C}`);
+
+x = (
+  (_C2 = class C {}),
+  babelHelpers.defineProperty(_C2, "t", _C2`${_C2.x}`),
+  _C2
+);
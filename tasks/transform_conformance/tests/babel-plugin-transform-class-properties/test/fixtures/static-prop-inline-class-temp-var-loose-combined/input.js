@@ -0,0 +1,5 @@
+// `Object.assign` returns the class, so can be nested
+x = class {
+  static a = 1;
+  static b = 2;
+};
@@ -0,0 +1,5 @@
+// `Object.assign` returns the class, so can be nested
+x = Object.assign(class {}, {
+  a: 1,
+  b: 2,
+});
@@ -0,0 +1,5 @@
+// Assignments evaluate to the value, not the class, so can't be nested
+x = class {
+  static a = 1;
+  static b = 2;
+};
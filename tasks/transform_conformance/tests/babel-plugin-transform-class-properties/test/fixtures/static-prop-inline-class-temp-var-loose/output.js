@@ -0,0 +1,4 @@
+var _Class;
+
+// Assignments evaluate to the value, not the class, so can't be nested
+x = ((_Class = class {}), (_Class.a = 1), (_Class.b = 2), _Class);
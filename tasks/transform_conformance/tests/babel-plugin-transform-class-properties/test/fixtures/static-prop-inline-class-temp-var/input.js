@@ -0,0 +1,34 @@
+// Simplifiable
+const A = class {
+  static x = 1;
+};
+const B = class B {
+  static x = 1;
+  static y = () => 2;
+  y = 3;
+};
+
+// Not simplifiable
+const D = class {
+  static x = this;
+};
+const D2 = class {
+  static x = 1;
+  static y = this;
+};
+const E = class E {
+  static x = () => E;
+};
+const F = class {
+  static x = 1;
+  static {
+    log();
+  }
+};
+const G = class {
+  static #x = 1;
+  static y = 2;
+};
+const H = class {
+  static [foo()] = 1;
+};
@@ -0,0 +1,35 @@
+var _Class, _Class2, _E, _Class3, _x, _Class4, _Class5;
+let _foo;
+
+// Simplifiable
+const A = babelHelpers.defineProperty(class {}, "x", 1);
+const B = babelHelpers.defineProperty(
+  babelHelpers.defineProperty(
+    class B {
+      constructor() {
+        babelHelpers.defineProperty(this, "y", 3);
+      }
+    },
+    "x",
+    1
+  ),
+  "y",
+  () => 2
+);
+
+// Not simplifiable
+const D =
+  ((_Class = class {}), babelHelpers.defineProperty(_Class, "x", _Class), _Class);
+const D2 =
+  ((_Class2 = class {}),
+  babelHelpers.defineProperty(_Class2, "x", 1),
+  babelHelpers.defineProperty(_Class2, "y", _Class2),
+  _Class2);
+const E =
+  ((_E = class E {}), babelHelpers.defineProperty(_E, "x", () => _E), _E);
+const F =
+  ((_Class3 = class {}), babelHelpers.defineProperty(_Class3, "x", 1), log(), _Class3);
+const G =
+  ((_Class4 = class {}), (_x = { _: 1 }), babelHelpers.defineProperty(_Class4, "y", 2), _Class4);
+const H =
+  ((_foo = foo()), (_Class5 = class {}), babelHelpers.defineProperty(_Class5, _foo, 1), _Class5);
@@ -0,0 +1,19 @@
+class C extends S {
+  static a = C.b;
+  static c = () => [this, C, super.d];
+  static {
+    C.e(this);
+    f(C);
+  }
+}
+
+class D {
+  static a = D;
+  static b = function () {
+    return D;
+  };
+}
+
+x = class E {
+  static a = [this, E];
+};
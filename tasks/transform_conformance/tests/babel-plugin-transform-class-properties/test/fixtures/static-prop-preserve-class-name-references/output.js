@@ -0,0 +1,18 @@
+var _C, _E;
+
+class C extends S {}
+_C = C;
+babelHelpers.defineProperty(C, "a", C.b);
+babelHelpers.defineProperty(C, "c", () => [_C, C, babelHelpers.superPropGet(_C, "d", _C)]);
+(() => {
+  C.e(_C);
+  f(C);
+})();
+
+class D {}
+babelHelpers.defineProperty(D, "a", D);
+babelHelpers.defineProperty(D, "b", function () {
+  return D;
+});
+
+x = (_E = class E {}, babelHelpers.defineProperty(_E, "a", [_E, _E]), _E);
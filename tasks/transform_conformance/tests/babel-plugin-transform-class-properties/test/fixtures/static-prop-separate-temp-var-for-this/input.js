@@ -0,0 +1,29 @@
+class C {
+  static a = this;
+  static b = C;
+  static c = () => [this, C];
+  static {
+    this.d = C;
+  }
+}
+
+let x = class D {
+  static a = this;
+  static b = D;
+};
+
+let y = class {
+  static a = this.b;
+};
+
+class E {
+  static a = E;
+}
+
+class F {
+  static a = this;
+}
+
+export default class {
+  static a = this;
+}
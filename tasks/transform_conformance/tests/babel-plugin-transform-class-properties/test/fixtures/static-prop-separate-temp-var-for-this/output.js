@@ -0,0 +1,33 @@
+var _C, _thisC, _D, _thisD, _Class, _thisClass, _E, _thisF, _thisClass2;
+class C {}
+_C = C;
+_thisC = C;
+babelHelpers.defineProperty(C, "a", _thisC);
+babelHelpers.defineProperty(C, "b", _C);
+babelHelpers.defineProperty(C, "c", () => [_thisC, _C]);
+_thisC.d = _C;
+
+let x =
+  ((_D = class D {}),
+  (_thisD = _D),
+  babelHelpers.defineProperty(_D, "a", _thisD),
+  babelHelpers.defineProperty(_D, "b", _D),
+  _D);
+
+let y =
+  ((_Class = class {}),
+  (_thisClass = _Class),
+  babelHelpers.defineProperty(_Class, "a", _thisClass.b),
+  _Class);
+
+class E {}
+_E = E;
+babelHelpers.defineProperty(E, "a", _E);
+
+class F {}
+_thisF = F;
+babelHelpers.defineProperty(F, "a", _thisF);
+
+export default class _Class2 {}
+_thisClass2 = _Class2;
+babelHelpers.defineProperty(_Class2, "a", _thisClass2);
@@ -0,0 +1,10 @@
+class C {
+  static x = this;
+  static y = C.x;
+  // In-built `name` and `length` properties are not writable, so still use `defineProperty`
+  static name = "n";
+}
+
+x = class {
+  static z = this;
+};
@@ -0,0 +1,10 @@
+var _C, _Class;
+
+class C {}
+_C = C;
+C.x = _C;
+C.y = _C.x;
+// In-built `name` and `length` properties are not writable, so still use `defineProperty`
+babelHelpers.defineProperty(C, "name", "n");
+
+x = ((_Class = class {}), (_Class.z = _Class), _Class);
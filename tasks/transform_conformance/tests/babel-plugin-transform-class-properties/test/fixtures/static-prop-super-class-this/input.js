@@ -0,0 +1,11 @@
+function f() {
+  class C extends this.Base {
+    static x = this.y;
+    static z = () => this;
+    // `this` in `extends` of nested class is `this` of `C`
+    static Inner = class extends this.Base {};
+  }
+  return class extends (this.Base, this.Other) {
+    static x = this.y;
+  };
+}
@@ -0,0 +1,14 @@
+function f() {
+  var _C, _Class;
+  class C extends this.Base {}
+  _C = C;
+  babelHelpers.defineProperty(C, "x", _C.y);
+  babelHelpers.defineProperty(C, "z", () => _C);
+  // `this` in `extends` of nested class is `this` of `C`
+  babelHelpers.defineProperty(C, "Inner", class extends _C.Base {});
+  return (
+    (_Class = class extends (this.Base, this.Other) {}),
+    babelHelpers.defineProperty(_Class, "x", _Class.y),
+    _Class
+  );
+}
@@ -0,0 +1,7 @@
+class C extends S {
+  static x = super.a.b.c;
+  static y = super["a"].b["c"];
+  static z = super.a.b.c();
+  static w = super.a.b.c = 1;
+  static g = () => super.a.b.c;
+}
@@ -0,0 +1,9 @@
+var _C;
+
+class C extends S {}
+_C = C;
+babelHelpers.defineProperty(C, "x", babelHelpers.superPropGet(_C, "a", _C).b.c);
+babelHelpers.defineProperty(C, "y", babelHelpers.superPropGet(_C, "a", _C).b["c"]);
+babelHelpers.defineProperty(C, "z", babelHelpers.superPropGet(_C, "a", _C).b.c());
+babelHelpers.defineProperty(C, "w", babelHelpers.superPropGet(_C, "a", _C).b.c = 1);
+babelHelpers.defineProperty(C, "g", () => babelHelpers.superPropGet(_C, "a", _C).b.c);
@@ -0,0 +1,10 @@
+let C = class C {
+  static x = _C;
+  static y = this;
+  static z = () => _C2;
+};
+
+class D {
+  static x = _D;
+  static y = D;
+}
@@ -0,0 +1,14 @@
+var _C3, _D2;
+
+let C = (
+  (_C3 = class C {}),
+  babelHelpers.defineProperty(_C3, "x", _C),
+  babelHelpers.defineProperty(_C3, "y", _C3),
+  babelHelpers.defineProperty(_C3, "z", () => _C2),
+  _C3
+);
+
+class D {}
+_D2 = D;
+babelHelpers.defineProperty(D, "x", _D);
+babelHelpers.defineProperty(D, "y", _D2);
@@ -0,0 +1,14 @@
+let _cls$C;
+
+let C = class C {
+  static x = this;
+  static y = C;
+  static #z = 1;
+  static getZ() {
+    return C.#z;
+  }
+};
+
+x = class {
+  static x = this;
+};
@@ -0,0 +1,21 @@
+var _cls$C2, _z, _cls$Class;
+
+let _cls$C;
+
+let C = (
+  (_cls$C2 = class C {
+    static getZ() {
+      return babelHelpers.assertClassBrand(_cls$C2, C, _z)._;
+    }
+  }),
+  babelHelpers.defineProperty(_cls$C2, "x", _cls$C2),
+  babelHelpers.defineProperty(_cls$C2, "y", _cls$C2),
+  (_z = { _: 1 }),
+  _cls$C2
+);
+
+x = (
+  (_cls$Class = class {}),
+  babelHelpers.defineProperty(_cls$Class, "x", _cls$Class),
+  _cls$Class
+);
@@ -0,0 +1,8 @@
+class C {
+  static isClass = typeof C === "function";
+  static a = typeof this;
+  static b = [void C, !C, -C.x, typeof C.y];
+  static {
+    if (typeof C !== "undefined") f();
+  }
+}
@@ -0,0 +1,10 @@
+var _C;
+
+class C {}
+_C = C;
+babelHelpers.defineProperty(C, "isClass", typeof _C === "function");
+babelHelpers.defineProperty(C, "a", typeof _C);
+babelHelpers.defineProperty(C, "b", [void _C, !_C, -_C.x, typeof _C.y]);
+(() => {
+  if (typeof _C !== "undefined") f();
+})();
@@ -0,0 +1,21 @@
+class C {
+  static x = eval("C = 1");
+  static y = this;
+}
+
+class D {
+  static x = () => eval("D = 1");
+  static y = this;
+}
+
+class E {
+  static x = this;
+}
+function f() {
+  eval("E = 1");
+}
+
+// Direct eval in program scope could reassign any top-level class
+class F {
+  static x = this;
+}
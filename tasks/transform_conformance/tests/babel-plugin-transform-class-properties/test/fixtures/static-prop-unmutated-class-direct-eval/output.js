@@ -0,0 +1,23 @@
+var _C, _D, _E, _F;
+
+class C {}
+_C = C;
+babelHelpers.defineProperty(C, "x", eval("C = 1"));
+babelHelpers.defineProperty(C, "y", _C);
+
+class D {}
+_D = D;
+babelHelpers.defineProperty(D, "x", () => eval("D = 1"));
+babelHelpers.defineProperty(D, "y", _D);
+
+class E {}
+_E = E;
+babelHelpers.defineProperty(E, "x", _E);
+function f() {
+  eval("E = 1");
+}
+
+// Direct eval in program scope could reassign any top-level class
+class F {}
+_F = F;
+babelHelpers.defineProperty(F, "x", _F);
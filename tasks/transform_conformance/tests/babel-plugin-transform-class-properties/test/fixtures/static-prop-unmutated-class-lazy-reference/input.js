@@ -0,0 +1,20 @@
+class Unmutated {
+  static immediate = Unmutated.y;
+  static lazy = () => Unmutated;
+  static lazyNested = function () {
+    return () => [Unmutated, this];
+  };
+}
+
+class MutatedLater {
+  static immediate = MutatedLater.y;
+  static lazy = () => MutatedLater;
+}
+function reset() {
+  MutatedLater = null;
+}
+
+class Shadowed {
+  static lazy = (Shadowed) => Shadowed;
+  static immediate = Shadowed;
+}
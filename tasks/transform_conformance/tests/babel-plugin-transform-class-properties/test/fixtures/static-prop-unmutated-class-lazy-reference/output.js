@@ -0,0 +1,20 @@
+var _MutatedLater;
+
+class Unmutated {}
+babelHelpers.defineProperty(Unmutated, "immediate", Unmutated.y);
+babelHelpers.defineProperty(Unmutated, "lazy", () => Unmutated);
+babelHelpers.defineProperty(Unmutated, "lazyNested", function () {
+  return () => [Unmutated, this];
+});
+
+class MutatedLater {}
+_MutatedLater = MutatedLater;
+babelHelpers.defineProperty(MutatedLater, "immediate", _MutatedLater.y);
+babelHelpers.defineProperty(MutatedLater, "lazy", () => _MutatedLater);
+function reset() {
+  MutatedLater = null;
+}
+
+class Shadowed {}
+babelHelpers.defineProperty(Shadowed, "lazy", (Shadowed) => Shadowed);
+babelHelpers.defineProperty(Shadowed, "immediate", Shadowed);
@@ -0,0 +1,25 @@
+class C {
+  static x = C.y;
+  static getSelf = () => this;
+  static getSelf2 = () => C;
+}
+
+class Reassigned {
+  static x = Reassigned.y;
+  static getSelf = () => this;
+}
+Reassigned = null;
+
+class Private {
+  static #p = 1;
+  static q = this.#p;
+}
+
+export class Exported {
+  static x = Exported.y;
+}
+
+class ExportedLater {
+  static x = ExportedLater.y;
+}
+export { ExportedLater };
@@ -0,0 +1,25 @@
+var _Reassigned, _Exported, _ExportedLater;
+
+class C {}
+babelHelpers.defineProperty(C, "x", C.y);
+babelHelpers.defineProperty(C, "getSelf", () => C);
+babelHelpers.defineProperty(C, "getSelf2", () => C);
+
+class Reassigned {}
+_Reassigned = Reassigned;
+babelHelpers.defineProperty(Reassigned, "x", _Reassigned.y);
+babelHelpers.defineProperty(Reassigned, "getSelf", () => _Reassigned);
+Reassigned = null;
+
+class Private {}
+var _p = { _: 1 };
+babelHelpers.defineProperty(Private, "q", babelHelpers.assertClassBrand(Private, Private, _p)._);
+
+export class Exported {}
+_Exported = Exported;
+babelHelpers.defineProperty(Exported, "x", _Exported.y);
+
+class ExportedLater {}
+_ExportedLater = ExportedLater;
+babelHelpers.defineProperty(ExportedLater, "x", _ExportedLater.y);
+export { ExportedLater };
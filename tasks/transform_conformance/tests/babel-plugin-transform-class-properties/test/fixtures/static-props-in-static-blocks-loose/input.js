@@ -0,0 +1,5 @@
+class C {
+  static a = this;
+  static name = "C";
+  static b;
+}
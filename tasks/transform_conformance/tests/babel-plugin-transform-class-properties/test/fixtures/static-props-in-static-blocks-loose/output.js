@@ -0,0 +1,11 @@
+class C {
+  static {
+    this.a = this;
+  }
+  static {
+    babelHelpers.defineProperty(this, "name", "C");
+  }
+  static {
+    this.b = void 0;
+  }
+}
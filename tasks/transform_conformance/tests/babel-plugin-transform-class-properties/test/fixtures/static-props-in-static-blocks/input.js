@@ -0,0 +1,32 @@
+class C extends S {
+  static a = this;
+  static b = C.a;
+  static c = super.x;
+  static {
+    log(this);
+  }
+  static d = () => this;
+  static e = function() { return this; };
+  static f;
+  static "g" = 1;
+  instance = this;
+}
+
+x = class {
+  static a = this;
+  static b = () => {
+    let x = 1;
+    return x;
+  };
+};
+
+// Not converted, as class has private property
+class D {
+  static #p = 1;
+  static a = this;
+}
+
+// Not converted, as static property has computed key
+class E {
+  static [k()] = this;
+}
@@ -0,0 +1,60 @@
+var _D, _E;
+
+let _k;
+
+class C extends S {
+  constructor(..._args) {
+    super(..._args);
+    babelHelpers.defineProperty(this, "instance", this);
+  }
+  static {
+    babelHelpers.defineProperty(this, "a", this);
+  }
+  static {
+    babelHelpers.defineProperty(this, "b", C.a);
+  }
+  static {
+    babelHelpers.defineProperty(this, "c", super.x);
+  }
+  static {
+    log(this);
+  }
+  static {
+    babelHelpers.defineProperty(this, "d", () => this);
+  }
+  static {
+    babelHelpers.defineProperty(this, "e", function() {
+      return this;
+    });
+  }
+  static {
+    babelHelpers.defineProperty(this, "f", void 0);
+  }
+  static {
+    babelHelpers.defineProperty(this, "g", 1);
+  }
+}
+
+x = class {
+  static {
+    babelHelpers.defineProperty(this, "a", this);
+  }
+  static {
+    babelHelpers.defineProperty(this, "b", () => {
+      let x = 1;
+      return x;
+    });
+  }
+};
+
+// Not converted, as class has private property
+class D {}
+_D = D;
+var _p = { _: 1 };
+babelHelpers.defineProperty(D, "a", _D);
+
+// Not converted, as static property has computed key
+_k = k();
+class E {}
+_E = E;
+babelHelpers.defineProperty(E, _k, _E);
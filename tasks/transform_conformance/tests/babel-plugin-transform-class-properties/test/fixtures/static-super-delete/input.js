@@ -0,0 +1,10 @@
+class Outer {
+  static prop = delete super.prop;
+  static computed = delete super[this.key];
+  static arrow = () => delete super.prop;
+  static nested = () => {
+    {
+      delete super.prop;
+    }
+  };
+}
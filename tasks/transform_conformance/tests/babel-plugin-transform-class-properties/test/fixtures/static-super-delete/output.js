@@ -0,0 +1,24 @@
+var _Outer;
+
+class Outer {}
+_Outer = Outer;
+
+babelHelpers.defineProperty(Outer, "prop", function() {
+  throw new ReferenceError("'delete super.prop' is invalid");
+}());
+
+babelHelpers.defineProperty(Outer, "computed", (babelHelpers.toPropertyKey(_Outer.key), function() {
+  throw new ReferenceError("'delete super[expr]' is invalid");
+}()));
+
+babelHelpers.defineProperty(Outer, "arrow", () => function() {
+  throw new ReferenceError("'delete super.prop' is invalid");
+}());
+
+babelHelpers.defineProperty(Outer, "nested", () => {
+  {
+    (function() {
+      throw new ReferenceError("'delete super.prop' is invalid");
+    })();
+  }
+});
@@ -0,0 +1,18 @@
+class C extends S {
+  static get p() {
+    return super.q;
+  }
+  static set p(v) {
+    super.q = v;
+  }
+  static get #r() {
+    return super.q;
+  }
+  static x = this.#r;
+  static y = class extends S {
+    static get p() {
+      return [this, super.q];
+    }
+    static accessor a = [this, super.q];
+  };
+}
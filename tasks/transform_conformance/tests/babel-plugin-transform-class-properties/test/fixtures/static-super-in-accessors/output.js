@@ -0,0 +1,21 @@
+var _C;
+
+class C extends S {
+  static get p() {
+    return super.q;
+  }
+  static set p(v) {
+    super.q = v;
+  }
+}
+_C = C;
+function _get_r() {
+  return babelHelpers.superPropGet(_C, "q", this);
+}
+babelHelpers.defineProperty(C, "x", _get_r.call(babelHelpers.assertClassBrand(_C, _C)));
+babelHelpers.defineProperty(C, "y", class extends S {
+  static get p() {
+    return [this, super.q];
+  }
+  static accessor a = [this, super.q];
+});
@@ -0,0 +1,13 @@
+class A {
+  static foo = { bar: 1 };
+  static fn() {}
+}
+class B extends A {
+  static x = super.foo?.bar;
+  static y = super.fn?.();
+  static z = super.foo?.bar?.baz;
+  static w = super["fn"]?.(1, 2);
+  static #m() {
+    return super.fn?.();
+  }
+}
@@ -0,0 +1,14 @@
+var _B;
+class A {
+  static fn() {}
+}
+babelHelpers.defineProperty(A, "foo", { bar: 1 });
+class B extends A {}
+_B = B;
+function _m() {
+  return babelHelpers.superPropGet(_B, "fn", this, 2)?.([]);
+}
+babelHelpers.defineProperty(B, "x", babelHelpers.superPropGet(_B, "foo", _B)?.bar);
+babelHelpers.defineProperty(B, "y", babelHelpers.superPropGet(_B, "fn", _B, 2)?.([]));
+babelHelpers.defineProperty(B, "z", babelHelpers.superPropGet(_B, "foo", _B)?.bar?.baz);
+babelHelpers.defineProperty(B, "w", babelHelpers.superPropGet(_B, "fn", _B, 2)?.([1, 2]));
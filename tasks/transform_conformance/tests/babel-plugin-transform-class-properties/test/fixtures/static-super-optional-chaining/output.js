@@ -0,0 +1,48 @@
+var _super$foo, _super$fn, _super$foo2, _super$fn2, _B;
+class A {
+  static fn() {}
+}
+babelHelpers.defineProperty(A, "foo", { bar: 1 });
+class B extends A {}
+_B = B;
+function _m() {
+  var _super$fn3;
+  return (_super$fn3 = babelHelpers.superPropGet(_B, "fn", this)) === null ||
+    _super$fn3 === void 0
+    ? void 0
+    : _super$fn3.call(this);
+}
+babelHelpers.defineProperty(
+  B,
+  "x",
+  (_super$foo = babelHelpers.superPropGet(_B, "foo", _B)) === null ||
+    _super$foo === void 0
+    ? void 0
+    : _super$foo.bar,
+);
+babelHelpers.defineProperty(
+  B,
+  "y",
+  (_super$fn = babelHelpers.superPropGet(_B, "fn", _B)) === null ||
+    _super$fn === void 0
+    ? void 0
+    : _super$fn.call(_B),
+);
+babelHelpers.defineProperty(
+  B,
+  "z",
+  (_super$foo2 = babelHelpers.superPropGet(_B, "foo", _B)) === null ||
+    _super$foo2 === void 0 ||
+    (_super$foo2 = _super$foo2.bar) === null ||
+    _super$foo2 === void 0
+    ? void 0
+    : _super$foo2.baz,
+);
+babelHelpers.defineProperty(
+  B,
+  "w",
+  (_super$fn2 = babelHelpers.superPropGet(_B, "fn", _B)) === null ||
+    _super$fn2 === void 0
+    ? void 0
+    : _super$fn2.call(_B, 1, 2),
+);
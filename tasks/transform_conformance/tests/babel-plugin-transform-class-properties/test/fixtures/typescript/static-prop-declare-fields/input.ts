@@ -0,0 +1,16 @@
+class C {
+  declare static x: number;
+  static y = this.z;
+  declare static w: string;
+  static v = C.x;
+  static u = class {
+    declare static x: number;
+    static y = this;
+  };
+}
+
+let D = class {
+  declare static x: number;
+  static y = this.x;
+  declare x: number;
+};
@@ -0,0 +1,14 @@
+var _Class, _C, _Class2;
+class C {}
+_C = C;
+babelHelpers.defineProperty(C, "y", _C.z);
+babelHelpers.defineProperty(C, "v", _C.x);
+babelHelpers.defineProperty(
+  C,
+  "u",
+  ((_Class = class {}), babelHelpers.defineProperty(_Class, "y", _Class), _Class)
+);
+let D =
+  ((_Class2 = class {}),
+  babelHelpers.defineProperty(_Class2, "y", _Class2.x),
+  _Class2);
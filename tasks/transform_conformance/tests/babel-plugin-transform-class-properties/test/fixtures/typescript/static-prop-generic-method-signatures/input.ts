@@ -0,0 +1,8 @@
+class C {
+  static a = this as unknown as {
+    m<const T extends C>(x: T, y: typeof C): T;
+    new <const C>(x: C): C;
+  };
+  static b = () => this as unknown as { m<const T>(x: T): typeof C };
+  static c = (<T>(x: T) => x)(this);
+}
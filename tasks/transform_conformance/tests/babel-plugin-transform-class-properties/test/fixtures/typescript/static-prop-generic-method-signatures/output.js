@@ -0,0 +1,7 @@
+var _C;
+
+class C {}
+_C = C;
+babelHelpers.defineProperty(C, "a", _C);
+babelHelpers.defineProperty(C, "b", () => _C);
+babelHelpers.defineProperty(C, "c", ((x) => x)(_C));
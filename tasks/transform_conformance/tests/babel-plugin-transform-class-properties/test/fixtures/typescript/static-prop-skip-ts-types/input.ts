@@ -0,0 +1,8 @@
+class C {
+  static x = this.y as C extends infer U ? U : never;
+  static f = <T,>(a: T): { [K in keyof T]: T[K] } => C.g<T>(a);
+  static o = {} as { m(x: typeof C): void; new (): C };
+  static {
+    let v: typeof this.x = <C>this;
+  }
+}
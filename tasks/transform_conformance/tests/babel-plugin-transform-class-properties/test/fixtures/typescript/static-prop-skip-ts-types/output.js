@@ -0,0 +1,10 @@
+var _C;
+
+class C {}
+_C = C;
+babelHelpers.defineProperty(C, "x", _C.y);
+babelHelpers.defineProperty(C, "f", (a) => _C.g(a));
+babelHelpers.defineProperty(C, "o", {});
+(() => {
+  let v = _C;
+})();
@@ -0,0 +1,10 @@
+class S {
+  static z = 1;
+}
+class C extends S {
+  static a = this.y as number;
+  static b = C satisfies object;
+  static c = this!.y;
+  static d = (super.z as any)!;
+  static e = <C>(<unknown>this);
+}
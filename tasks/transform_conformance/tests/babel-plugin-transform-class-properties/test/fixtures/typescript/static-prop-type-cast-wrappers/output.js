@@ -0,0 +1,12 @@
+var _C;
+
+class S {}
+babelHelpers.defineProperty(S, "z", 1);
+
+class C extends S {}
+_C = C;
+babelHelpers.defineProperty(C, "a", _C.y);
+babelHelpers.defineProperty(C, "b", _C);
+babelHelpers.defineProperty(C, "c", _C.y);
+babelHelpers.defineProperty(C, "d", babelHelpers.superPropGet(_C, "z", _C));
+babelHelpers.defineProperty(C, "e", _C);
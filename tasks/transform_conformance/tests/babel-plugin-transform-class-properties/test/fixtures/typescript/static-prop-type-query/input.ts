@@ -0,0 +1,10 @@
+class C {
+  static x = y as typeof C;
+  static z = <typeof C>w;
+  static f = (v: typeof C): typeof C => v;
+  static g = () => {
+    type T = typeof C;
+    return y as T;
+  };
+  static h = C;
+}
@@ -0,0 +1,11 @@
+var _C;
+
+class C {}
+_C = C;
+babelHelpers.defineProperty(C, "x", y);
+babelHelpers.defineProperty(C, "z", w);
+babelHelpers.defineProperty(C, "f", (v) => v);
+babelHelpers.defineProperty(C, "g", () => {
+  return y;
+});
+babelHelpers.defineProperty(C, "h", _C);
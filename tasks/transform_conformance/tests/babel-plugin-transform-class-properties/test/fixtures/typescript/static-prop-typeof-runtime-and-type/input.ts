@@ -0,0 +1,4 @@
+class C {
+  static isClass = (typeof C === "function") as typeof C extends Function ? true : false;
+  static self: typeof C | undefined = typeof C === "function" ? C : undefined;
+}
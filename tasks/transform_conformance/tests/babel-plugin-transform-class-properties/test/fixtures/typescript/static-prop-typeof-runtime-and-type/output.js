@@ -0,0 +1,6 @@
+var _C;
+
+class C {}
+_C = C;
+babelHelpers.defineProperty(C, "isClass", typeof _C === "function");
+babelHelpers.defineProperty(C, "self", typeof _C === "function" ? _C : undefined);
@@ -0,0 +1,17 @@
+function dec(target: any, key?: string) {}
+
+@dec
+class C {
+  static a: number = 1;
+  static b: C = this;
+  static c = this.a;
+  @dec
+  static m(): C {
+    return this;
+  }
+}
+
+@dec
+export class D {
+  static a = this;
+}
@@ -0,0 +1,26 @@
+var _ref, _C, _C2, _D;
+
+function dec(target, key) {}
+
+let C = _C2 = class C {
+  static m() {
+    return this;
+  }
+};
+_C = C;
+babelHelpers.defineProperty(C, "a", 1);
+babelHelpers.defineProperty(C, "b", _C);
+babelHelpers.defineProperty(C, "c", _C.a);
+babelHelpers.decorate([
+  dec,
+  babelHelpers.decorateMetadata("design:type", Function),
+  babelHelpers.decorateMetadata("design:paramtypes", []),
+  babelHelpers.decorateMetadata("design:returntype", typeof (_ref = typeof _C2 === "undefined" && _C2) === "function" ? _ref : Object)
+], C, "m", null);
+C = _C2 = babelHelpers.decorate([dec], C);
+
+let D = class D {};
+_D = D;
+babelHelpers.defineProperty(D, "a", _D);
+D = babelHelpers.decorate([dec], D);
+export { D };